@@ -0,0 +1,160 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::fmt::{self, Display};
+
+/// The answer to one part of a puzzle.
+///
+/// Parts can return any type that converts into an `Answer`: unsigned and signed integers,
+/// `String`/`&str`, or multi-line text such as the pictures drawn by CRT-style puzzles.
+/// Non-negative signed integers are stored as unsigned so that `Answer::from(6_i64)` and
+/// `Answer::from(6_u32)` compare equal.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Answer {
+    Unsigned(u64),
+    Signed(i64),
+    Text(String),
+}
+
+impl Answer {
+    /// Text answers that span several lines, which are printed starting on a new line.
+    pub fn is_multiline(&self) -> bool {
+        matches!(self, Answer::Text(text) if text.contains('\n'))
+    }
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Unsigned(n) => write!(f, "{}", n),
+            Answer::Signed(n) => write!(f, "{}", n),
+            Answer::Text(text) => write!(f, "{}", text),
+        }
+    }
+}
+
+macro_rules! impl_from_unsigned {
+    ($($t:ty),*) => {
+        $(impl From<$t> for Answer {
+            fn from(n: $t) -> Self {
+                Answer::Unsigned(n as u64)
+            }
+        })*
+    };
+}
+
+macro_rules! impl_from_signed {
+    ($($t:ty),*) => {
+        $(impl From<$t> for Answer {
+            fn from(n: $t) -> Self {
+                if n < 0 {
+                    Answer::Signed(n as i64)
+                } else {
+                    Answer::Unsigned(n as u64)
+                }
+            }
+        })*
+    };
+}
+
+impl_from_unsigned!(u8, u16, u32, u64, usize);
+impl_from_signed!(i8, i16, i32, i64, isize);
+
+impl From<String> for Answer {
+    fn from(text: String) -> Self {
+        Answer::Text(text)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(text: &str) -> Self {
+        Answer::Text(text.to_string())
+    }
+}
+
+impl From<char> for Answer {
+    fn from(c: char) -> Self {
+        Answer::Text(c.to_string())
+    }
+}
+
+/// What running a part produced.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Outcome {
+    Solved(Answer),
+    Unsolved,
+    /// The part returned an error, described by its message.
+    Failed(String),
+}
+
+/// Return types accepted for parts: `Option<T>` or `Result<T, E>` for any `T` that converts
+/// into an [`Answer`] and any error `E` that can be displayed.
+pub trait IntoOutcome {
+    fn into_outcome(self) -> Outcome;
+}
+
+impl<T: Into<Answer>> IntoOutcome for Option<T> {
+    fn into_outcome(self) -> Outcome {
+        match self {
+            Some(answer) => Outcome::Solved(answer.into()),
+            None => Outcome::Unsolved,
+        }
+    }
+}
+
+impl<T: Into<Answer>, E: Display> IntoOutcome for Result<T, E> {
+    fn into_outcome(self) -> Outcome {
+        match self {
+            Ok(answer) => Outcome::Solved(answer.into()),
+            Err(e) => Outcome::Failed(e.to_string()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_integer_answers_compare_by_value() {
+        assert_eq!(Answer::from(6_u32), Answer::from(6_i64));
+        assert_eq!(
+            Answer::from(70_000_000_000_u64),
+            Answer::Unsigned(70_000_000_000)
+        );
+        assert_eq!(Answer::from(-3_i32), Answer::Signed(-3));
+        assert_ne!(Answer::from(6_u8), Answer::from("6"));
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(Answer::from(-3_i64).to_string(), "-3");
+        assert_eq!(Answer::from(u64::MAX).to_string(), "18446744073709551615");
+        assert_eq!(Answer::from("CMZ").to_string(), "CMZ");
+    }
+
+    #[test]
+    fn test_into_outcome() {
+        assert_eq!(
+            Some(6_u32).into_outcome(),
+            Outcome::Solved(Answer::Unsigned(6))
+        );
+        assert_eq!(None::<String>.into_outcome(), Outcome::Unsolved);
+        assert_eq!(
+            Ok::<_, String>("CMZ").into_outcome(),
+            Outcome::Solved(Answer::from("CMZ"))
+        );
+        assert_eq!(
+            Err::<u32, _>("Invalid direction").into_outcome(),
+            Outcome::Failed("Invalid direction".into())
+        );
+    }
+
+    #[test]
+    fn test_multiline() {
+        assert!(Answer::from("##..\n#..#").is_multiline());
+        assert!(!Answer::from("CMZ").is_multiline());
+        assert!(!Answer::from(42_u32).is_multiline());
+    }
+}
@@ -0,0 +1,281 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::fmt::{self, Display};
+use std::path::PathBuf;
+use std::{fs, io};
+
+/// Checked-in file holding the answers locked with `--lock`, relative to the repository root.
+pub const ANSWERS_FILE: &str = "answers.txt";
+
+const HEADER: &str = "# Locked answers, written by `cargo solve <day> -- --lock`.
+# <day> <part> <input hash> <answer>
+";
+
+/// Stable FNV-1a hash of an input, used to detect when an input file was replaced.
+pub fn input_hash(input: &str) -> String {
+    let hash = input.bytes().fold(0xcbf29ce484222325_u64, |hash, byte| {
+        (hash ^ byte as u64).wrapping_mul(0x100000001b3)
+    });
+    format!("{:016x}", hash)
+}
+
+/// Outcome of checking a part's answer against the locked answers.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Verdict {
+    Correct,
+    Wrong {
+        expected: String,
+    },
+    /// No answer was locked for this part.
+    Unverified,
+    /// An answer was locked, but for a different input file.
+    InputChanged,
+}
+
+impl Verdict {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Verdict::Correct => "correct",
+            Verdict::Wrong { .. } => "wrong",
+            Verdict::Unverified => "unverified",
+            Verdict::InputChanged => "input_changed",
+        }
+    }
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Verdict::Correct => write!(f, "✔ correct"),
+            Verdict::Wrong { expected } if expected.contains('\n') => {
+                write!(f, "✘ wrong, expected:\n{}", expected)
+            }
+            Verdict::Wrong { expected } => write!(f, "✘ wrong, expected {}", expected),
+            Verdict::Unverified => write!(f, "? unverified"),
+            Verdict::InputChanged => write!(f, "? unverified, input changed since locking"),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+struct LockedAnswer {
+    day: u8,
+    part: u8,
+    input_hash: String,
+    answer: String,
+}
+
+/// Name of the answers file of `year`: [`ANSWERS_FILE`] for the main year, `answers-YYYY.txt`
+/// for a separate one, see [`separate_year`](crate::separate_year).
+pub fn file_name(year: Option<i16>) -> String {
+    match year {
+        None => ANSWERS_FILE.to_string(),
+        Some(year) => format!("answers-{}.txt", year),
+    }
+}
+
+/// The known answers stored in [`ANSWERS_FILE`], or in the [`file_name`] of a separate year.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct AnswerBook {
+    entries: Vec<LockedAnswer>,
+}
+
+impl AnswerBook {
+    pub fn path(year: Option<i16>) -> PathBuf {
+        crate::root_dir().join(file_name(year))
+    }
+
+    /// Loads the answers file of `year`. A missing file is treated as an empty book.
+    pub fn load(year: Option<i16>) -> io::Result<AnswerBook> {
+        match fs::read_to_string(Self::path(year)) {
+            Ok(contents) => Ok(AnswerBook::parse(&contents)),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(AnswerBook::default()),
+            Err(e) => Err(e),
+        }
+    }
+
+    pub fn save(&self, year: Option<i16>) -> io::Result<()> {
+        fs::write(Self::path(year), self.to_string())
+    }
+
+    pub fn parse(contents: &str) -> AnswerBook {
+        let entries = contents
+            .lines()
+            .filter(|line| !line.starts_with('#') && !line.trim().is_empty())
+            .filter_map(|line| {
+                let mut fields = line.splitn(4, ' ');
+                Some(LockedAnswer {
+                    day: fields.next()?.parse().ok()?,
+                    part: fields.next()?.parse().ok()?,
+                    input_hash: fields.next()?.to_string(),
+                    answer: unescape(fields.next()?),
+                })
+            })
+            .collect();
+        AnswerBook { entries }
+    }
+
+    /// Records `answer` as the correct answer for a part, replacing any previous entry.
+    pub fn lock(&mut self, day: u8, part: u8, input_hash: &str, answer: &str) {
+        self.entries
+            .retain(|entry| (entry.day, entry.part) != (day, part));
+        self.entries.push(LockedAnswer {
+            day,
+            part,
+            input_hash: input_hash.to_string(),
+            answer: answer.to_string(),
+        });
+        self.entries.sort_by_key(|entry| (entry.day, entry.part));
+    }
+
+    /// Whether any answer was locked for `day`.
+    pub fn has_day(&self, day: u8) -> bool {
+        self.entries.iter().any(|entry| entry.day == day)
+    }
+
+    /// Whether an answer was locked for a part of `day`.
+    pub fn has_part(&self, day: u8, part: u8) -> bool {
+        self.entries
+            .iter()
+            .any(|entry| (entry.day, entry.part) == (day, part))
+    }
+
+    pub fn verify(&self, day: u8, part: u8, input_hash: &str, answer: Option<&str>) -> Verdict {
+        let entry = match self
+            .entries
+            .iter()
+            .find(|entry| (entry.day, entry.part) == (day, part))
+        {
+            Some(entry) => entry,
+            None => return Verdict::Unverified,
+        };
+
+        if entry.input_hash != input_hash {
+            Verdict::InputChanged
+        } else if answer == Some(entry.answer.as_str()) {
+            Verdict::Correct
+        } else {
+            Verdict::Wrong {
+                expected: entry.answer.clone(),
+            }
+        }
+    }
+}
+
+impl Display for AnswerBook {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", HEADER)?;
+        for entry in &self.entries {
+            writeln!(
+                f,
+                "{:02} {} {} {}",
+                entry.day,
+                entry.part,
+                entry.input_hash,
+                escape(&entry.answer)
+            )?;
+        }
+        Ok(())
+    }
+}
+
+/// Keeps multi-line answers on a single line of the answers file.
+fn escape(answer: &str) -> String {
+    answer.replace('\\', "\\\\").replace('\n', "\\n")
+}
+
+fn unescape(answer: &str) -> String {
+    let mut out = String::with_capacity(answer.len());
+    let mut chars = answer.chars();
+    while let Some(c) = chars.next() {
+        match (c, chars.clone().next()) {
+            ('\\', Some('n')) => {
+                out.push('\n');
+                chars.next();
+            }
+            ('\\', Some('\\')) => {
+                out.push('\\');
+                chars.next();
+            }
+            _ => out.push(c),
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_file_name() {
+        assert_eq!(file_name(None), "answers.txt");
+        assert_eq!(file_name(Some(2021)), "answers-2021.txt");
+    }
+
+    #[test]
+    fn test_input_hash() {
+        assert_eq!(input_hash(""), "cbf29ce484222325");
+        assert_eq!(input_hash("a"), "af63dc4c8601ec8c");
+        assert_ne!(input_hash("R 4\nU 4\n"), input_hash("R 4\nU 5\n"));
+    }
+
+    #[test]
+    fn test_verify() {
+        let mut book = AnswerBook::default();
+        book.lock(7, 1, "abc", "95437");
+
+        assert_eq!(book.verify(7, 1, "abc", Some("95437")), Verdict::Correct);
+        assert_eq!(
+            book.verify(7, 1, "abc", Some("95438")),
+            Verdict::Wrong {
+                expected: "95437".into()
+            }
+        );
+        assert_eq!(
+            book.verify(7, 1, "abc", None),
+            Verdict::Wrong {
+                expected: "95437".into()
+            }
+        );
+        assert_eq!(
+            book.verify(7, 1, "def", Some("95437")),
+            Verdict::InputChanged
+        );
+        assert_eq!(book.verify(7, 2, "abc", Some("1")), Verdict::Unverified);
+        assert!(book.has_day(7));
+        assert!(!book.has_day(8));
+        assert!(book.has_part(7, 1));
+        assert!(!book.has_part(7, 2));
+    }
+
+    #[test]
+    fn test_lock_replaces_entry() {
+        let mut book = AnswerBook::default();
+        book.lock(9, 1, "abc", "13");
+        book.lock(1, 2, "def", "45000");
+        book.lock(9, 1, "ghi", "14");
+
+        assert_eq!(book.verify(9, 1, "ghi", Some("14")), Verdict::Correct);
+        assert_eq!(
+            book.to_string(),
+            format!("{}01 2 def 45000\n09 1 ghi 14\n", HEADER)
+        );
+    }
+
+    #[test]
+    fn test_roundtrip_multiline_answer() {
+        let mut book = AnswerBook::default();
+        book.lock(10, 2, "abc", "#..#\n\\..#");
+        book.lock(5, 1, "def", "CMZ");
+
+        let parsed = AnswerBook::parse(&book.to_string());
+        assert_eq!(parsed, book);
+        assert_eq!(
+            parsed.verify(10, 2, "abc", Some("#..#\n\\..#")),
+            Verdict::Correct
+        );
+    }
+}
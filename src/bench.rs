@@ -0,0 +1,159 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+/// Stop sampling after this many runs, even if the time budget is not used up.
+const MAX_SAMPLES: u32 = 100_000;
+/// Take at least this many samples, even if the time budget is used up.
+const MIN_SAMPLES: u32 = 5;
+
+/// How often a step is repeated in `--bench` mode.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BenchOptions {
+    /// Untimed runs before sampling starts.
+    pub warmup: u32,
+    /// Fixed number of samples. When unset, samples are taken until `time_budget` is used up.
+    pub iterations: Option<u32>,
+    pub time_budget: Duration,
+}
+
+impl Default for BenchOptions {
+    fn default() -> Self {
+        BenchOptions {
+            warmup: 3,
+            iterations: None,
+            time_budget: Duration::from_secs(1),
+        }
+    }
+}
+
+/// Summary of the samples taken for one step.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct BenchStats {
+    pub samples: u32,
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+    pub stddev: Duration,
+}
+
+impl BenchStats {
+    pub fn from_samples(samples: &mut [Duration]) -> Option<BenchStats> {
+        if samples.is_empty() {
+            return None;
+        }
+
+        samples.sort();
+        let len = samples.len();
+        let median = if len.is_multiple_of(2) {
+            (samples[len / 2 - 1] + samples[len / 2]) / 2
+        } else {
+            samples[len / 2]
+        };
+
+        let nanos: Vec<f64> = samples.iter().map(|s| s.as_nanos() as f64).collect();
+        let mean = nanos.iter().sum::<f64>() / len as f64;
+        let variance = nanos.iter().map(|n| (n - mean).powi(2)).sum::<f64>() / len as f64;
+
+        Some(BenchStats {
+            samples: len as u32,
+            min: samples[0],
+            median,
+            mean: Duration::from_nanos(mean.round() as u64),
+            stddev: Duration::from_nanos(variance.sqrt().round() as u64),
+        })
+    }
+}
+
+/// Warms up and then repeatedly times `step` according to `options`.
+pub fn measure<T>(options: &BenchOptions, step: impl FnMut() -> T) -> BenchStats {
+    measure_with(options, || {}, step)
+}
+
+/// Like [`measure`], but calls `before_run` before every run of `step`, outside of the timing.
+pub fn measure_with<T>(
+    options: &BenchOptions,
+    mut before_run: impl FnMut(),
+    mut step: impl FnMut() -> T,
+) -> BenchStats {
+    for _ in 0..options.warmup {
+        before_run();
+        black_box(step());
+    }
+
+    let mut samples = Vec::new();
+    let started = Instant::now();
+
+    loop {
+        before_run();
+        let timer = Instant::now();
+        black_box(step());
+        samples.push(timer.elapsed());
+
+        let taken = samples.len() as u32;
+        let done = match options.iterations {
+            Some(iterations) => taken >= iterations,
+            None => {
+                taken >= MAX_SAMPLES
+                    || (taken >= MIN_SAMPLES && started.elapsed() >= options.time_budget)
+            }
+        };
+        if done {
+            break;
+        }
+    }
+
+    BenchStats::from_samples(&mut samples).unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn nanos(values: &[u64]) -> Vec<Duration> {
+        values.iter().map(|n| Duration::from_nanos(*n)).collect()
+    }
+
+    #[test]
+    fn test_stats_odd_samples() {
+        let stats = BenchStats::from_samples(&mut nanos(&[30, 10, 20])).unwrap();
+        assert_eq!(stats.samples, 3);
+        assert_eq!(stats.min, Duration::from_nanos(10));
+        assert_eq!(stats.median, Duration::from_nanos(20));
+        assert_eq!(stats.mean, Duration::from_nanos(20));
+        assert_eq!(stats.stddev, Duration::from_nanos(8));
+    }
+
+    #[test]
+    fn test_stats_even_samples() {
+        let stats = BenchStats::from_samples(&mut nanos(&[4, 2, 8, 6])).unwrap();
+        assert_eq!(stats.median, Duration::from_nanos(5));
+        assert_eq!(stats.mean, Duration::from_nanos(5));
+        assert_eq!(stats.stddev, Duration::from_nanos(2));
+    }
+
+    #[test]
+    fn test_stats_empty() {
+        assert_eq!(BenchStats::from_samples(&mut []), None);
+    }
+
+    #[test]
+    fn test_measure_fixed_iterations() {
+        let mut calls = 0;
+        let options = BenchOptions {
+            warmup: 2,
+            iterations: Some(7),
+            time_budget: Duration::ZERO,
+        };
+        let stats = measure(&options, || calls += 1);
+        assert_eq!(stats.samples, 7);
+        assert_eq!(calls, 9);
+
+        let mut before = 0;
+        measure_with(&options, || before += 1, || ());
+        assert_eq!(before, 9);
+    }
+}
@@ -0,0 +1,108 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// Puzzles unlock at midnight EST, which is 05:00 UTC.
+const UNLOCK_OFFSET_SECS: i64 = 5 * 60 * 60;
+const SECS_PER_DAY: i64 = 24 * 60 * 60;
+
+/// Seconds since the Unix epoch.
+pub fn now() -> i64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |elapsed| elapsed.as_secs() as i64)
+}
+
+/// Days since the Unix epoch of a date, see <http://howardhinnant.github.io/date_algorithms.html>.
+fn days_from_civil(year: i64, month: i64, day: i64) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let day_of_year = (153 * ((month + 9) % 12) + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146097 + day_of_era - 719468
+}
+
+/// The date of a day since the Unix epoch, as `(year, month, day)`.
+fn civil_from_days(days: i64) -> (i64, i64, i64) {
+    let days = days + 719468;
+    let era = days.div_euclid(146097);
+    let day_of_era = days - era * 146097;
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36524 - day_of_era / 146096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month_index = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * month_index + 2) / 5 + 1;
+    let month = if month_index < 10 {
+        month_index + 3
+    } else {
+        month_index - 9
+    };
+    let year = year_of_era + era * 400 + i64::from(month <= 2);
+    (year, month, day)
+}
+
+/// Number of puzzles of an event. Since 2025, events have 12 days instead of 25.
+pub fn days_in_event(year: i16) -> u8 {
+    if year >= 2025 {
+        12
+    } else {
+        25
+    }
+}
+
+/// When the puzzle of a day unlocks, in seconds since the Unix epoch.
+pub fn unlock_time(year: i16, day: u8) -> i64 {
+    days_from_civil(year.into(), 12, day.into()) * SECS_PER_DAY + UNLOCK_OFFSET_SECS
+}
+
+/// The days of `year` whose puzzles are unlocked at `now`, in ascending order.
+pub fn unlocked_days(year: i16, now: i64) -> Vec<u8> {
+    (1..=days_in_event(year))
+        .filter(|day| unlock_time(year, *day) <= now)
+        .collect()
+}
+
+/// The most recent event at `now`: this year's from December 1st on, last year's before.
+pub fn current_event(now: i64) -> i16 {
+    let (year, month, _) = civil_from_days((now - UNLOCK_OFFSET_SECS).div_euclid(SECS_PER_DAY));
+    let year = if month == 12 { year } else { year - 1 };
+    year as i16
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_dates() {
+        assert_eq!(days_from_civil(1970, 1, 1), 0);
+        assert_eq!(days_from_civil(2000, 3, 1), 11017);
+        assert_eq!(civil_from_days(11017), (2000, 3, 1));
+        assert_eq!(civil_from_days(days_from_civil(2024, 2, 29)), (2024, 2, 29));
+    }
+
+    #[test]
+    fn test_unlocked_days() {
+        // 2022-12-01T05:00:00Z
+        let first_unlock = 1669870800;
+        assert_eq!(unlock_time(2022, 1), first_unlock);
+        assert_eq!(unlocked_days(2022, first_unlock - 1), vec![]);
+        assert_eq!(unlocked_days(2022, first_unlock), vec![1]);
+        assert_eq!(
+            unlocked_days(2022, first_unlock + 6 * SECS_PER_DAY),
+            (1..=7).collect::<Vec<_>>()
+        );
+        assert_eq!(unlocked_days(2025, unlock_time(2026, 1)).len(), 12);
+    }
+
+    #[test]
+    fn test_current_event() {
+        let first_unlock = unlock_time(2022, 1);
+        assert_eq!(current_event(first_unlock - 1), 2021);
+        assert_eq!(current_event(first_unlock), 2022);
+        assert_eq!(current_event(unlock_time(2023, 1) - 1), 2022);
+    }
+}
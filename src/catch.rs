@@ -0,0 +1,80 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::any::Any;
+use std::cell::{Cell, RefCell};
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::Once;
+
+thread_local! {
+    static CATCHING: Cell<bool> = const { Cell::new(false) };
+    static MESSAGE: RefCell<Option<String>> = const { RefCell::new(None) };
+}
+
+fn payload_message(payload: &(dyn Any + Send)) -> &str {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        message
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message
+    } else {
+        "Box<dyn Any>"
+    }
+}
+
+/// Replaces the panic hook with one that records the message and location of panics raised
+/// inside [`catch_panic`] instead of printing them. Other panics are printed as before.
+fn install_hook() {
+    static HOOK: Once = Once::new();
+    HOOK.call_once(|| {
+        let default_hook = panic::take_hook();
+        panic::set_hook(Box::new(move |info| {
+            if !CATCHING.with(Cell::get) {
+                return default_hook(info);
+            }
+            let message = payload_message(info.payload());
+            let message = match info.location() {
+                Some(location) => format!("panicked at {}: {}", location, message),
+                None => format!("panicked: {}", message),
+            };
+            MESSAGE.with(|slot| *slot.borrow_mut() = Some(message));
+        }));
+    });
+}
+
+/// Runs `f`, turning a panic into an error that describes it, e.g.
+/// `panicked at src/days/day06.rs:12:9: index out of bounds: the len is 4 but the index is 4`.
+pub fn catch_panic<T>(f: impl FnOnce() -> T) -> Result<T, String> {
+    install_hook();
+
+    let was_catching = CATCHING.with(|catching| catching.replace(true));
+    let result = panic::catch_unwind(AssertUnwindSafe(f));
+    CATCHING.with(|catching| catching.set(was_catching));
+
+    result.map_err(|payload| {
+        MESSAGE
+            .with(|slot| slot.borrow_mut().take())
+            .unwrap_or_else(|| format!("panicked: {}", payload_message(payload.as_ref())))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_catch_panic() {
+        assert_eq!(catch_panic(|| 42), Ok(42));
+
+        let error = catch_panic(|| {
+            let values: Vec<u32> = vec![];
+            values[0]
+        })
+        .unwrap_err();
+        assert!(error.starts_with("panicked at src/catch.rs:"), "{}", error);
+        assert!(error.ends_with("index out of bounds: the len is 0 but the index is 0"));
+
+        let error = catch_panic(|| panic!("invalid {}", "input")).unwrap_err();
+        assert!(error.ends_with(": invalid input"), "{}", error);
+    }
+}
@@ -0,0 +1,786 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use super::{finish, CommandError, CommandResult};
+use advent_of_code::answers::Verdict;
+use advent_of_code::config;
+use advent_of_code::history::{self, HistoryEntry};
+use advent_of_code::log::{self, Verbosity};
+use advent_of_code::readme;
+use advent_of_code::report::{self, PartRecord, Status, PARSE_STEP, REPORT_FILE_ENV};
+use advent_of_code::runner::{self, InputSource, RunOptions};
+use advent_of_code::{years, ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
+use std::collections::BTreeMap;
+use std::io::Read;
+use std::path::PathBuf;
+use std::process::{Command, Stdio};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::mpsc;
+use std::thread::{self, JoinHandle};
+use std::time::{Duration, Instant};
+use std::{env, fs, process};
+
+struct Args {
+    /// Set by `--year <year>`: the separate year whose days are run, `None` for the main year.
+    year: Option<i16>,
+    /// The days to run, in order.
+    days: Vec<u8>,
+    isolated: bool,
+    /// Number of days run at the same time.
+    jobs: usize,
+    /// Set by `--timeout <secs>`: stop a day's binary if it runs for longer than this.
+    timeout: Option<Duration>,
+    /// Set by `--record`: append the benchmark timings to the history file.
+    record: bool,
+    /// Set by `--compare`: compare the benchmark timings to the last recorded run.
+    compare: bool,
+    /// Set by `--threshold <percent>`: how much slower a step may get before `--compare`
+    /// points it out.
+    threshold: f64,
+    /// Set by `--readme`: write the timings to the benchmark table in the README.
+    readme: bool,
+    options: RunOptions,
+}
+
+const DEFAULT_THRESHOLD: f64 = 10.0;
+
+fn parse_args(args: &mut pico_args::Arguments) -> Result<Args, pico_args::Error> {
+    let year = advent_of_code::separate_year(args.opt_value_from_str(["-y", "--year"])?);
+    let mut options = RunOptions::from_args(args)?;
+    if options.lock {
+        // answers are locked one day at a time, after checking them on the website.
+        return Err(pico_args::Error::ArgumentParsingFailed {
+            cause: "--lock can only be used with `cargo solve <day>`".into(),
+        });
+    }
+    match options.input {
+        InputSource::Puzzle => options.answers = runner::load_answers(year),
+        InputSource::Example => {}
+        InputSource::File(_) | InputSource::Stdin => {
+            return Err(pico_args::Error::ArgumentParsingFailed {
+                cause: "--input can only be used with `cargo solve <day>`".into(),
+            })
+        }
+    }
+    let mut days = args
+        .opt_value_from_fn("--days", runner::parse_days)?
+        .unwrap_or_else(|| (1..=25).collect());
+    let skipped: Vec<u8> = args.values_from_fn("--skip", runner::parse_days)?.concat();
+    days.retain(|day| !skipped.contains(day));
+    if args.contains("--only-solved") {
+        // answers are not loaded for example runs, but the selection still uses them.
+        let answers = options
+            .answers
+            .clone()
+            .or_else(|| runner::load_answers(year));
+        days.retain(|day| {
+            answers
+                .as_ref()
+                .is_some_and(|answers| answers.has_day(*day))
+        });
+    }
+
+    let config = config::get();
+    let timeout = args
+        .opt_value_from_fn("--timeout", runner::parse_seconds)?
+        .or(config.timeout);
+    // a running day can only be stopped when it runs in a process of its own.
+    let mut isolated =
+        args.contains("--isolated") || timeout.is_some() || options.part_timeout.is_some();
+    let mut jobs = args
+        .opt_value_from_str(["-j", "--jobs"])?
+        .or(config.jobs)
+        .unwrap_or(1);
+    if jobs == 0 {
+        return Err(pico_args::Error::ArgumentParsingFailed {
+            cause: "--jobs must be at least 1".into(),
+        });
+    }
+    if jobs > 1 {
+        if options.bench.is_some() {
+            // days running next to each other would skew each other's timings.
+            eprintln!("--bench runs one day at a time, ignoring --jobs.");
+            jobs = 1;
+        } else {
+            // only output of child processes can be held back until it is a day's turn.
+            isolated = true;
+        }
+    }
+
+    let record = args.contains("--record");
+    let compare = args.contains("--compare");
+    if (record || compare) && options.bench.is_none() {
+        return Err(pico_args::Error::ArgumentParsingFailed {
+            cause: "--record and --compare need --bench".into(),
+        });
+    }
+    if (record || compare) && options.input != InputSource::Puzzle {
+        // timings of the examples are not comparable to those of the puzzle inputs.
+        return Err(pico_args::Error::ArgumentParsingFailed {
+            cause: "--record and --compare only work with puzzle inputs".into(),
+        });
+    }
+    let threshold = args
+        .opt_value_from_str("--threshold")?
+        .unwrap_or(DEFAULT_THRESHOLD);
+    let readme = args.contains("--readme");
+    if readme && options.input != InputSource::Puzzle {
+        return Err(pico_args::Error::ArgumentParsingFailed {
+            cause: "--readme only works with puzzle inputs".into(),
+        });
+    }
+    if readme && year.is_some() {
+        // the table links to the modules of the main year.
+        return Err(pico_args::Error::ArgumentParsingFailed {
+            cause: "--readme only works for the main year".into(),
+        });
+    }
+
+    Ok(Args {
+        year,
+        days,
+        isolated,
+        jobs,
+        timeout,
+        record,
+        compare,
+        threshold,
+        readme,
+        options,
+    })
+}
+
+/// How far a day got, apart from the results of its parts.
+enum DayStatus {
+    /// The day has no module or binary yet.
+    NotScaffolded,
+    /// The day's binary did not compile. Holds the compiler errors.
+    BuildFailed(String),
+    /// The day could not run to completion, e.g. its input is missing or its binary exited
+    /// with a non-zero status.
+    Failed(String),
+    /// The day's binary was stopped by `--timeout` after running for this long.
+    TimedOut(Duration),
+    Ran,
+}
+
+struct DayReport {
+    day: u8,
+    status: DayStatus,
+    records: Vec<PartRecord>,
+    /// What the day's binary printed, when run in a child process.
+    stdout: String,
+    stderr: String,
+}
+
+impl DayReport {
+    fn new(day: u8, status: DayStatus) -> DayReport {
+        DayReport {
+            day,
+            status,
+            records: vec![],
+            stdout: String::new(),
+            stderr: String::new(),
+        }
+    }
+}
+
+/// Runs a registered day inside this process.
+fn run_in_process(year: Option<i16>, day: u8, options: &RunOptions) -> DayReport {
+    let solution = match years::get(year, day) {
+        Some(solution) => solution,
+        None => return DayReport::new(day, DayStatus::NotScaffolded),
+    };
+    match options.input.read(year, day) {
+        Ok(input) => DayReport {
+            records: solution.run(&input, options),
+            ..DayReport::new(day, DayStatus::Ran)
+        },
+        Err(e) => DayReport::new(day, DayStatus::Failed(e.to_string())),
+    }
+}
+
+/// Number of stderr lines shown for a failed day.
+const EXCERPT_LINES: usize = 20;
+
+/// The day binaries, built once before `--isolated` runs them.
+struct Build {
+    /// Where cargo put the binaries, e.g. `target/release`.
+    dir: PathBuf,
+    /// Names of the binaries that did not compile, see [`advent_of_code::bin_name`].
+    failed: Vec<String>,
+    /// Cargo's output, holding the compiler errors of the failed days.
+    stderr: String,
+}
+
+impl Build {
+    /// Builds the binaries of the scaffolded `days` with a single cargo invocation, see
+    /// [`runner::profile`]. `--keep-going` lets the other days build when one of them is broken.
+    /// The running `aoc` binary is left out, Windows does not let cargo replace it.
+    ///
+    /// Only errors in `src/bin/` are told apart per day. The day modules are part of the library
+    /// that this binary links too, so cargo stops on their errors before `cargo all` starts.
+    fn run(year: Option<i16>, days: &[u8]) -> Build {
+        let dir = runner::binary_dir();
+        let profile = runner::profile();
+
+        let bins: Vec<String> = days
+            .iter()
+            .filter(|day| bin_path(year, **day).exists())
+            .map(|day| advent_of_code::bin_name(year, *day))
+            .collect();
+        if bins.is_empty() {
+            // without any `--bin`, cargo would build every binary.
+            return Build::from_output(dir, String::new());
+        }
+
+        println!("Building day binaries ({} profile)...", profile);
+        let cmd = runner::cargo()
+            .args(["build", "--keep-going", "--profile", &profile])
+            .args(bins.iter().flat_map(|bin| ["--bin", bin]))
+            .output()
+            .unwrap();
+        Build::from_output(dir, String::from_utf8_lossy(&cmd.stderr).into_owned())
+    }
+
+    /// Reads which binaries failed to compile from cargo's output.
+    fn from_output(dir: PathBuf, stderr: String) -> Build {
+        // e.g. error: could not compile `advent_of_code` (bin "08") due to 1 previous error
+        let failed = stderr
+            .lines()
+            .filter(|line| line.starts_with("error: could not compile"))
+            .filter_map(|line| Some(line.split("(bin \"").nth(1)?.split('"').next()?.to_string()))
+            .collect();
+
+        Build {
+            dir,
+            failed,
+            stderr,
+        }
+    }
+
+    fn binary(&self, bin_name: &str) -> PathBuf {
+        self.dir
+            .join(format!("{}{}", bin_name, env::consts::EXE_SUFFIX))
+    }
+
+    /// The compiler errors that point into a day's binary.
+    fn errors(&self, bin_name: &str) -> String {
+        let unix_path = format!("src/bin/{}.rs", bin_name);
+        let windows_path = format!("src\\bin\\{}.rs", bin_name);
+
+        // diagnostics start with an unindented line such as `error[E0308]: mismatched types`.
+        let mut blocks: Vec<Vec<&str>> = vec![];
+        for line in self.stderr.lines() {
+            match blocks.last_mut() {
+                Some(block) if !line.starts_with(|c: char| c.is_ascii_alphabetic()) => {
+                    block.push(line)
+                }
+                _ => blocks.push(vec![line]),
+            }
+        }
+
+        blocks
+            .into_iter()
+            .filter(|block| block[0].starts_with("error"))
+            .filter(|block| {
+                block
+                    .iter()
+                    .any(|line| line.contains(&unix_path) || line.contains(&windows_path))
+            })
+            .flatten()
+            .take(EXCERPT_LINES)
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+/// The source file of a day's binary, `src/bin/NN.rs`. Missing for days not scaffolded yet.
+fn bin_path(year: Option<i16>, day: u8) -> PathBuf {
+    advent_of_code::root_dir()
+        .join("src")
+        .join("bin")
+        .join(format!("{}.rs", advent_of_code::bin_name(year, day)))
+}
+
+/// The last lines a day's binary printed to stderr.
+fn runtime_errors(stderr: &str) -> String {
+    let lines: Vec<&str> = stderr.lines().collect();
+    lines[lines.len().saturating_sub(EXCERPT_LINES)..].join("\n")
+}
+
+fn read_in_background(mut pipe: impl Read + Send + 'static) -> JoinHandle<String> {
+    thread::spawn(move || {
+        let mut output = vec![];
+        #[allow(unused_must_use)]
+        {
+            pipe.read_to_end(&mut output);
+        }
+        String::from_utf8_lossy(&output).into_owned()
+    })
+}
+
+/// Runs a day's binary in a child process and collects its result records.
+/// The child is killed if it runs for longer than `timeout`.
+fn run_isolated(
+    year: Option<i16>,
+    day: u8,
+    options: &RunOptions,
+    build: &Build,
+    timeout: Option<Duration>,
+) -> DayReport {
+    let bin_name = advent_of_code::bin_name(year, day);
+    if !bin_path(year, day).exists() {
+        return DayReport::new(day, DayStatus::NotScaffolded);
+    }
+    if build.failed.contains(&bin_name) {
+        return DayReport::new(day, DayStatus::BuildFailed(build.errors(&bin_name)));
+    }
+
+    let report_path =
+        env::temp_dir().join(format!("aoc_report_{}_{}.jsonl", process::id(), bin_name));
+
+    #[allow(unused_must_use)]
+    {
+        fs::remove_file(&report_path);
+    }
+
+    let child = Command::new(build.binary(&bin_name))
+        .args(options.to_args())
+        .env(REPORT_FILE_ENV, &report_path)
+        // the output is captured, so the child cannot tell whether it ends up in a terminal.
+        .env(config::COLOR_ENV, config::child_color())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn();
+    let mut child = match child {
+        Ok(child) => child,
+        Err(e) => {
+            let reason = format!("could not run {}: {}", build.binary(&bin_name).display(), e);
+            return DayReport::new(day, DayStatus::Failed(reason));
+        }
+    };
+
+    // drain the pipes while waiting, a child blocked on a full pipe would never exit.
+    let stdout = read_in_background(child.stdout.take().unwrap());
+    let stderr = read_in_background(child.stderr.take().unwrap());
+
+    let started = Instant::now();
+    let exit_status = match timeout {
+        Some(timeout) => loop {
+            if let Some(status) = child.try_wait().unwrap() {
+                break Some(status);
+            }
+            if started.elapsed() >= timeout {
+                #[allow(unused_must_use)]
+                {
+                    child.kill();
+                }
+                child.wait().unwrap();
+                break None;
+            }
+            thread::sleep(Duration::from_millis(5));
+        },
+        None => Some(child.wait().unwrap()),
+    };
+    let elapsed = started.elapsed();
+
+    let stdout = stdout.join().unwrap();
+    let stderr = stderr.join().unwrap();
+
+    let records = fs::read_to_string(&report_path)
+        .map(|contents| report::read_records(&contents))
+        .unwrap_or_default();
+
+    #[allow(unused_must_use)]
+    {
+        fs::remove_file(&report_path);
+    }
+
+    let status = match exit_status {
+        None => DayStatus::TimedOut(elapsed),
+        Some(status) if status.success() => DayStatus::Ran,
+        // the part that timed out is reported in the records.
+        Some(status) if status.code() == Some(runner::TIMEOUT_EXIT_CODE) => DayStatus::Ran,
+        Some(status) => DayStatus::Failed(format!("{}\n{}", status, runtime_errors(&stderr))),
+    };
+
+    DayReport {
+        day,
+        status,
+        records,
+        stdout,
+        stderr,
+    }
+}
+
+fn print_header(day: u8) {
+    println!("----------");
+    println!("{}| Day {:02} |{}", ANSI_BOLD, day, ANSI_RESET);
+    println!("----------");
+}
+
+/// Prints the output of a day that ran in a child process, and what kept a day from running,
+/// if anything.
+fn print_report(report: &DayReport, options: &RunOptions) {
+    if !report.stdout.is_empty() {
+        println!("{}", report.stdout.trim());
+    }
+    if options.verbosity > Verbosity::Quiet {
+        eprint!("{}", report.stderr);
+    }
+
+    match &report.status {
+        DayStatus::NotScaffolded => println!("Not scaffolded."),
+        DayStatus::BuildFailed(errors) => println!("Build failed:\n{}", errors.trim_end()),
+        DayStatus::Failed(reason) => println!("Failed: {}", reason.trim_end()),
+        DayStatus::TimedOut(elapsed) => println!("Timed out after {:.2?}.", elapsed),
+        DayStatus::Ran => {}
+    }
+}
+
+/// Runs `days`, `jobs` of them at a time, and prints their reports in order of the days.
+fn run_days(
+    days: &[u8],
+    jobs: usize,
+    options: &RunOptions,
+    run: impl Fn(u8) -> DayReport + Sync,
+) -> Vec<DayReport> {
+    if jobs == 1 {
+        return days
+            .iter()
+            .map(|&day| {
+                print_header(day);
+                let report = run(day);
+                print_report(&report, options);
+                report
+            })
+            .collect();
+    }
+
+    let next_day = AtomicUsize::new(0);
+    let (sender, receiver) = mpsc::channel();
+
+    thread::scope(|scope| {
+        for _ in 0..jobs {
+            let sender = sender.clone();
+            let (next_day, run) = (&next_day, &run);
+            scope.spawn(move || {
+                while let Some(&day) = days.get(next_day.fetch_add(1, Ordering::Relaxed)) {
+                    sender.send(run(day)).unwrap();
+                }
+            });
+        }
+        drop(sender);
+
+        // reports arrive as days finish, hold them back until all earlier days are printed.
+        let mut pending = BTreeMap::new();
+        let mut reports: Vec<DayReport> = vec![];
+        for report in receiver {
+            pending.insert(report.day, report);
+            while let Some(report) = days.get(reports.len()).and_then(|day| pending.remove(day)) {
+                print_header(report.day);
+                print_report(&report, options);
+                reports.push(report);
+            }
+        }
+        reports
+    })
+}
+
+fn format_median(records: &[PartRecord], day: u8, part: u8) -> String {
+    records
+        .iter()
+        .find(|record| record.day == day && record.part == part)
+        .filter(|record| record.status == Status::Solved)
+        .map(|record| format!("{:.2?}", record.elapsed))
+        .unwrap_or_else(|| "-".into())
+}
+
+/// Prints the median timing of every benchmarked step as a table.
+fn print_bench_table(records: &[PartRecord]) {
+    let mut days: Vec<u8> = records.iter().map(|record| record.day).collect();
+    days.dedup();
+
+    println!("----------");
+    println!("| Day | Parse | Part 1 | Part 2 |");
+    println!("| :---: | :---: | :---: | :---: |");
+    for day in days {
+        println!(
+            "| {:02} | {} | {} | {} |",
+            day,
+            format_median(records, day, PARSE_STEP),
+            format_median(records, day, 1),
+            format_median(records, day, 2)
+        );
+    }
+    println!("----------");
+}
+
+fn step_name(part: u8) -> String {
+    match part {
+        PARSE_STEP => "Parse".into(),
+        part => format!("Part {}", part),
+    }
+}
+
+/// Compares the timings of this run to the last run recorded with the same profile and prints
+/// the steps that changed, pointing out those that got slower than `threshold` percent.
+fn print_comparison(year: Option<i16>, records: &[PartRecord], profile: &str, threshold: f64) {
+    let history = match history::load(year) {
+        Ok(history) => history,
+        Err(e) => {
+            eprintln!("Could not read {}: {}", history::path(year).display(), e);
+            return;
+        }
+    };
+    let baseline = history::last_run(&history, profile);
+    let Some(first) = baseline.first() else {
+        println!(
+            "No benchmark recorded for the {} profile yet, run with --record to create a baseline.",
+            profile
+        );
+        return;
+    };
+
+    println!(
+        "{}Compared to {}{} ({} profile):",
+        ANSI_BOLD, first.commit, ANSI_RESET, profile
+    );
+    println!("| Day | Step | Baseline | Current | Change |");
+    println!("| :---: | :---: | :---: | :---: | :---: |");
+    let changes = history::compare(&baseline, records);
+    let mut slower = 0;
+    for change in &changes {
+        let marker = if change.is_regression(threshold) {
+            slower += 1;
+            " ⚠ slower"
+        } else {
+            ""
+        };
+        println!(
+            "| {:02} | {} | {:.2?} | {:.2?} | {:+.1}%{} |",
+            change.day,
+            step_name(change.part),
+            change.baseline,
+            change.current,
+            change.percent(),
+            marker
+        );
+    }
+    if slower > 0 {
+        println!(
+            "{}{} step(s) got more than {}% slower.{}",
+            ANSI_BOLD, slower, threshold, ANSI_RESET
+        );
+    }
+}
+
+fn record_history(year: Option<i16>, records: &[PartRecord], profile: &str) {
+    let commit = history::current_commit();
+    let entries = HistoryEntry::from_records(history::run_id(), &commit, profile, records);
+    match history::append(year, &entries) {
+        Ok(()) => println!(
+            "Recorded {} timing(s) of {} to {}.",
+            entries.len(),
+            commit,
+            history::file_name(year)
+        ),
+        Err(e) => eprintln!("Could not write {}: {}", history::path(year).display(), e),
+    }
+}
+
+/// Prints how many days and parts ran into problems and lists them.
+/// Returns `false` if anything other than a missing day went wrong.
+fn print_summary(reports: &[DayReport]) -> bool {
+    let parts = || reports.iter().flat_map(|report| &report.records);
+    let count_parts = |status: Status| {
+        parts()
+            .filter(|record| record.part != PARSE_STEP && record.status == status)
+            .count()
+    };
+    let count_days =
+        |f: fn(&DayStatus) -> bool| reports.iter().filter(|report| f(&report.status)).count();
+
+    let unsolved = count_parts(Status::Unsolved);
+    let failed_steps = parts()
+        .filter(|record| record.status == Status::Failed)
+        .count();
+    let build_failed = count_days(|status| matches!(status, DayStatus::BuildFailed(_)));
+    let failed_days = count_days(|status| matches!(status, DayStatus::Failed(_)));
+    let timed_out = count_days(|status| matches!(status, DayStatus::TimedOut(_)));
+
+    println!(
+        "{}Summary:{} {} solved, {} unsolved, {} failed, {} build failure(s), {} day(s) failed to run, {} timed out, {} not scaffolded",
+        ANSI_BOLD,
+        ANSI_RESET,
+        count_parts(Status::Solved),
+        unsolved,
+        failed_steps,
+        build_failed,
+        failed_days,
+        timed_out,
+        count_days(|status| matches!(status, DayStatus::NotScaffolded)),
+    );
+
+    for report in reports {
+        match &report.status {
+            DayStatus::BuildFailed(_) => println!("  Day {:02}: build failed", report.day),
+            DayStatus::Failed(reason) => println!(
+                "  Day {:02}: failed: {}",
+                report.day,
+                reason.lines().next().unwrap_or_default()
+            ),
+            DayStatus::TimedOut(elapsed) => {
+                println!("  Day {:02}: timed out after {:.2?}", report.day, elapsed)
+            }
+            DayStatus::NotScaffolded | DayStatus::Ran => {}
+        }
+        for record in &report.records {
+            let step = match record.part {
+                PARSE_STEP => "parse".to_string(),
+                part => format!("part {}", part),
+            };
+            match record.status {
+                Status::Unsolved => println!("  Day {:02}, {}: not solved", report.day, step),
+                Status::Failed => println!(
+                    "  Day {:02}, {}: failed: {}",
+                    report.day,
+                    step,
+                    record.error.as_deref().unwrap_or_default()
+                ),
+                Status::Solved => {}
+            }
+        }
+    }
+
+    unsolved + failed_steps + build_failed + failed_days + timed_out == 0
+}
+
+/// Prints how many parts matched their locked answers. Returns `false` if any part was wrong.
+fn print_verdicts(records: &[PartRecord]) -> bool {
+    let verdicts: Vec<&Verdict> = records
+        .iter()
+        .filter_map(|record| record.verdict.as_ref())
+        .collect();
+    if verdicts.is_empty() {
+        return true;
+    }
+    let count = |f: fn(&Verdict) -> bool| verdicts.iter().filter(|v| f(v)).count();
+
+    let wrong = count(|v| matches!(v, Verdict::Wrong { .. }));
+    println!(
+        "{}Answers:{} {} correct, {} wrong, {} unverified, {} with changed input",
+        ANSI_BOLD,
+        ANSI_RESET,
+        count(|v| *v == Verdict::Correct),
+        wrong,
+        count(|v| *v == Verdict::Unverified),
+        count(|v| *v == Verdict::InputChanged),
+    );
+
+    for record in records {
+        if let Some(Verdict::Wrong { expected }) = &record.verdict {
+            println!(
+                "  Day {:02}, part {}: got {}, expected {}",
+                record.day,
+                record.part,
+                record.answer.as_deref().unwrap_or("nothing"),
+                expected
+            );
+        }
+    }
+
+    wrong == 0
+}
+
+/// `aoc all`: runs every selected day and summarizes the results.
+pub fn run(mut args: pico_args::Arguments) -> CommandResult {
+    let parsed = parse_args(&mut args)?;
+    finish(args)?;
+    let args = parsed;
+    log::set_verbosity(args.options.verbosity);
+
+    let build = args.isolated.then(|| Build::run(args.year, &args.days));
+
+    if let Some(year) = args.year {
+        println!("{}Advent of Code {}{}", ANSI_BOLD, year, ANSI_RESET);
+    }
+    let reports = run_days(&args.days, args.jobs, &args.options, |day| match &build {
+        Some(build) => run_isolated(args.year, day, &args.options, build, args.timeout),
+        None => run_in_process(args.year, day, &args.options),
+    });
+    let records: Vec<PartRecord> = reports
+        .iter()
+        .flat_map(|report| report.records.iter().cloned())
+        .collect();
+
+    if args.options.bench.is_some() {
+        print_bench_table(&records);
+        // days run in process are timed with the profile of this binary.
+        let profile = match args.isolated {
+            true => runner::profile(),
+            false => runner::current_profile(),
+        };
+        // compare first, so a run is not compared to itself.
+        if args.compare {
+            print_comparison(args.year, &records, &profile, args.threshold);
+        }
+        if args.record {
+            record_history(args.year, &records, &profile);
+        }
+    }
+
+    let total = runner::total_elapsed(&records);
+
+    println!(
+        "{}Total:{} {}{:.2}ms{}",
+        ANSI_BOLD,
+        ANSI_RESET,
+        ANSI_ITALIC,
+        total.as_secs_f64() * 1000_f64,
+        ANSI_RESET
+    );
+
+    if args.readme {
+        match readme::update(&records) {
+            Ok(()) => println!("Updated the benchmark table in README.md."),
+            Err(e) => eprintln!("Could not update README.md: {}", e),
+        }
+    }
+
+    let all_ran = print_summary(&reports);
+    let all_correct = print_verdicts(&records);
+    if !all_ran || !all_correct {
+        return Err(CommandError::Reported);
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const BIN_ERROR: &str = r#"   Compiling advent_of_code v0.8.0 (/root/crate)
+error[E0425]: cannot find value `x` in this scope
+ --> src/bin/08.rs:2:5
+  |
+2 |     x;
+  |     ^ not found in this scope
+
+For more information about this error, try `rustc --explain E0425`.
+error: could not compile `advent_of_code` (bin "08") due to 1 previous error
+warning: build failed, waiting for other jobs to finish...
+"#;
+
+    #[test]
+    fn test_bin_build_failure_fails_its_day() {
+        let build = Build::from_output(PathBuf::new(), BIN_ERROR.to_string());
+        assert_eq!(build.failed, vec!["08"]);
+        let errors = build.errors("08");
+        assert!(errors.starts_with("error[E0425]: cannot find value `x` in this scope"));
+        assert!(errors.contains("src/bin/08.rs:2:5"));
+        assert_eq!(build.errors("07"), "");
+    }
+}
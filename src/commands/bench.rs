@@ -0,0 +1,29 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use super::{all, solve, CommandResult};
+use advent_of_code::runner;
+
+/// `aoc bench [<day>]`: benchmarks one day, or all days if none is given. Shorthand for
+/// `aoc solve <day> --bench` and `aoc all --bench`.
+pub fn run(mut args: pico_args::Arguments) -> CommandResult {
+    // taken out first, so the day is found wherever `--year` is given.
+    let year: Option<String> = args.opt_value_from_str(["-y", "--year"])?;
+    let mut args = args.finish();
+    let single_day = args
+        .first()
+        .and_then(|arg| arg.to_str())
+        .is_some_and(|arg| runner::parse_day(arg).is_ok());
+    args.push("--bench".into());
+    if let Some(year) = year {
+        args.extend(["--year".into(), year.into()]);
+    }
+
+    let args = pico_args::Arguments::from_vec(args);
+    if single_day {
+        solve::run(args)
+    } else {
+        all::run(args)
+    }
+}
@@ -0,0 +1,243 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use super::{aoc_cli, finish, CommandError, CommandResult};
+use advent_of_code::download::{check, next_backup_path};
+use advent_of_code::{calendar, config, root_dir, runner, separate_year, ANSI_BOLD, ANSI_RESET};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::{env, fs, io, process};
+
+fn remove_file(path: &Path) {
+    #[allow(unused_must_use)]
+    {
+        fs::remove_file(path);
+    }
+}
+
+/// Takes the days to download: a day, a list of ranges such as `1-7,9`, or `--all`.
+/// Returns `None` for `--all`.
+fn days_arg(args: &mut pico_args::Arguments) -> Result<Option<Vec<u8>>, CommandError> {
+    if args.contains("--all") {
+        return Ok(None);
+    }
+    args.free_from_fn(runner::parse_days)
+        .map(Some)
+        .map_err(|e| match e {
+            pico_args::Error::MissingArgument => CommandError::failed(
+                "Need to specify a day, a range of days or --all. example: `cargo download 7`, `cargo download 1-7` or `cargo download --all`",
+            ),
+            e => CommandError::Args(e),
+        })
+}
+
+/// Where the input of a day is stored, relative to the repository root.
+fn input_path(year: Option<i16>, day: u8) -> PathBuf {
+    let mut path = config::get().inputs_dir.clone();
+    if let Some(year) = separate_year(year) {
+        path.push(year.to_string());
+    }
+    path.join(format!("{:02}.txt", day))
+}
+
+/// Whether an input was downloaded already. Scaffolding creates empty input files.
+fn is_present(path: &Path) -> bool {
+    fs::metadata(root_dir().join(path)).is_ok_and(|meta| meta.len() > 0)
+}
+
+/// `aoc download <day>`: downloads the puzzle input of a day with aoc-cli. With `--all` or a
+/// range of days, downloads every unlocked day whose input is still missing. Existing inputs
+/// are only replaced with `--force`.
+pub fn run(mut args: pico_args::Arguments) -> CommandResult {
+    let year: Option<i16> = args.opt_value_from_str(["-y", "--year"])?;
+    let force = args.contains("--force");
+    let days = days_arg(&mut args)?;
+    finish(args)?;
+
+    match days.as_deref() {
+        Some(&[day]) => {
+            let path = input_path(year, day);
+            if !force && is_present(&path) {
+                return Err(CommandError::failed(format!(
+                    "\"{}\" exists already, pass --force to replace it.",
+                    path.display()
+                )));
+            }
+            download(year.or(config::get().year), day, &path)
+        }
+        _ => download_missing(year, days, force),
+    }
+}
+
+/// Downloads the missing inputs of `days`, or of all unlocked days for `None`, and prints a
+/// summary. Inputs that exist and are not empty are left alone, unless `force` is set.
+fn download_missing(year: Option<i16>, days: Option<Vec<u8>>, force: bool) -> CommandResult {
+    let now = calendar::now();
+    // unlike for a single day, the year has to be known to tell which days are unlocked.
+    let event = year
+        .or(config::get().year)
+        .unwrap_or_else(|| calendar::current_event(now));
+    let unlocked = calendar::unlocked_days(event, now);
+    let mut days = days.unwrap_or_else(|| unlocked.clone());
+    let count = calendar::days_in_event(event);
+    if days.iter().any(|day| *day > count) {
+        println!(
+            "The {} event has {} days, skipping the others.",
+            event, count
+        );
+        days.retain(|day| *day <= count);
+    }
+    aoc_cli()?;
+
+    let mut downloaded = vec![];
+    let mut present = vec![];
+    let mut locked = vec![];
+    let mut failed = vec![];
+    for day in days {
+        let path = input_path(year, day);
+        if !unlocked.contains(&day) {
+            locked.push(day);
+        } else if !force && is_present(&path) {
+            present.push(day);
+        } else {
+            println!("----------");
+            match download(Some(event), day, &path) {
+                Ok(()) => downloaded.push(day),
+                Err(CommandError::Reported) => failed.push((day, "aoc-cli failed".to_string())),
+                Err(e) => failed.push((day, e.to_string())),
+            }
+        }
+    }
+
+    let list = |days: &[u8]| {
+        let days: Vec<String> = days.iter().map(|day| format!("{:02}", day)).collect();
+        days.join(", ")
+    };
+    println!("----------");
+    println!(
+        "{}Summary ({}):{} {} downloaded, {} already present, {} not unlocked yet, {} failed",
+        ANSI_BOLD,
+        event,
+        ANSI_RESET,
+        downloaded.len(),
+        present.len(),
+        locked.len(),
+        failed.len()
+    );
+    if !present.is_empty() {
+        println!("  Already present: {}", list(&present));
+    }
+    if !locked.is_empty() {
+        println!("  Not unlocked yet: {}", list(&locked));
+    }
+    for (day, reason) in &failed {
+        println!("  Day {:02}: {}", day, reason);
+    }
+
+    if !failed.is_empty() {
+        return Err(CommandError::Reported);
+    }
+    Ok(())
+}
+
+/// Downloads the input of a day to `input_path`, relative to the repository root.
+fn download(year: Option<i16>, day: u8, input_path: &Path) -> CommandResult {
+    // acquire a temp file path to write aoc-cli output to. It is unique to this process and day,
+    // so downloads running at the same time do not overwrite each other's input.
+    // aoc-cli expects this file not to be present - delete just in case.
+    let tmp_file_path = env::temp_dir().join(format!("aoc_input_{}_{:02}.tmp", process::id(), day));
+    remove_file(&tmp_file_path);
+
+    let result = fetch(year, day, &tmp_file_path, input_path);
+    remove_file(&tmp_file_path);
+    result
+}
+
+fn fetch(year: Option<i16>, day: u8, tmp_file_path: &Path, input_path: &Path) -> CommandResult {
+    let mut cmd = aoc_cli()?;
+    let mut cmd_args = vec![];
+
+    if let Some(year) = year {
+        cmd_args.push("--year".into());
+        cmd_args.push(year.to_string());
+    }
+
+    cmd_args.append(&mut vec![
+        "--input-file".into(),
+        tmp_file_path.to_string_lossy().to_string(),
+        "--day".into(),
+        day.to_string(),
+        "download".into(),
+    ]);
+
+    println!("Downloading input with >aoc {}", cmd_args.join(" "));
+
+    match cmd.args(cmd_args).output() {
+        Ok(cmd_output) => {
+            io::stdout()
+                .write_all(&cmd_output.stdout)
+                .expect("could not write cmd stdout to pipe.");
+            io::stderr()
+                .write_all(&cmd_output.stderr)
+                .expect("could not write cmd stderr to pipe.");
+            if !cmd_output.status.success() {
+                return Err(CommandError::Reported);
+            }
+        }
+        Err(e) => {
+            return Err(CommandError::failed(format!(
+                "failed to spawn aoc-cli: {}",
+                e
+            )));
+        }
+    }
+
+    let target = root_dir().join(input_path);
+    if let Some(parent) = target.parent() {
+        fs::create_dir_all(parent).map_err(|e| {
+            CommandError::failed(format!("could not create {}: {}", parent.display(), e))
+        })?;
+    }
+    let contents = fs::read_to_string(tmp_file_path)
+        .map_err(|e| CommandError::failed(format!("could not read downloaded input: {}", e)))?;
+    if let Err(reason) = check(&contents) {
+        return Err(CommandError::failed(format!(
+            "not writing \"{}\", {}.",
+            input_path.display(),
+            reason
+        )));
+    }
+
+    if is_present(input_path) {
+        let previous = fs::read_to_string(&target).unwrap_or_default();
+        if previous == contents {
+            println!("---");
+            println!("🎄 \"{}\" is up to date.", input_path.display());
+            return Ok(());
+        }
+        let backup = next_backup_path(&target);
+        fs::copy(&target, &backup).map_err(|e| {
+            CommandError::failed(format!("could not back up the previous input: {}", e))
+        })?;
+        println!(
+            "Kept the previous input as \"{}\".",
+            backup.strip_prefix(root_dir()).unwrap_or(&backup).display()
+        );
+    }
+
+    match fs::write(&target, contents) {
+        Ok(_) => {
+            println!("---");
+            println!(
+                "🎄 Successfully wrote input to \"{}\".",
+                input_path.display()
+            );
+            Ok(())
+        }
+        Err(e) => Err(CommandError::failed(format!(
+            "could not write input file: {}",
+            e
+        ))),
+    }
+}
@@ -0,0 +1,73 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use advent_of_code::runner;
+/// Fails on leftover arguments, shared with `cargo solve <day>`.
+pub use advent_of_code::runner::finish;
+use std::fmt::{self, Display};
+use std::process::Command;
+
+pub mod all;
+pub mod bench;
+pub mod download;
+pub mod progress;
+pub mod scaffold;
+pub mod solve;
+pub mod submit;
+pub mod test;
+
+/// Why a subcommand failed.
+#[derive(Debug)]
+pub enum CommandError {
+    /// The arguments could not be parsed.
+    Args(pico_args::Error),
+    /// The command could not do its job, the message says why.
+    Failed(String),
+    /// The command already printed what went wrong, e.g. the summary of `aoc all`.
+    Reported,
+}
+
+impl CommandError {
+    pub fn failed(message: impl Into<String>) -> CommandError {
+        CommandError::Failed(message.into())
+    }
+}
+
+impl From<pico_args::Error> for CommandError {
+    fn from(e: pico_args::Error) -> Self {
+        CommandError::Args(e)
+    }
+}
+
+impl Display for CommandError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CommandError::Args(e) => write!(f, "Failed to process arguments: {}", e),
+            CommandError::Failed(message) => write!(f, "{}", message),
+            CommandError::Reported => Ok(()),
+        }
+    }
+}
+
+pub type CommandResult = Result<(), CommandError>;
+
+/// Takes the day a command works on, the first free argument.
+pub fn day_arg(args: &mut pico_args::Arguments) -> Result<u8, CommandError> {
+    args.free_from_fn(runner::parse_day).map_err(|e| match e {
+        pico_args::Error::MissingArgument => {
+            CommandError::failed("Need to specify a day (as integer). example: `cargo aoc solve 7`")
+        }
+        e => CommandError::Args(e),
+    })
+}
+
+/// The `aoc` command of aoc-cli, used to talk to the Advent of Code website.
+pub fn aoc_cli() -> Result<Command, CommandError> {
+    if Command::new("aoc").arg("-V").output().is_err() {
+        return Err(CommandError::failed(
+            "command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it.",
+        ));
+    }
+    Ok(Command::new("aoc"))
+}
@@ -0,0 +1,60 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use super::{finish, CommandError, CommandResult};
+use advent_of_code::answers::{self, AnswerBook};
+use advent_of_code::{separate_year, year_file_path, years, ANSI_BOLD, ANSI_RESET};
+use std::fs;
+
+fn mark(done: bool, symbol: &str) -> &str {
+    if done {
+        symbol
+    } else {
+        "-"
+    }
+}
+
+/// `aoc progress`: shows which days are scaffolded, have an input and have locked answers.
+pub fn run(mut args: pico_args::Arguments) -> CommandResult {
+    let year = separate_year(args.opt_value_from_str(["-y", "--year"])?);
+    finish(args)?;
+    let answers = AnswerBook::load(year).map_err(|e| {
+        CommandError::failed(format!(
+            "could not read {}: {}",
+            answers::file_name(year),
+            e
+        ))
+    })?;
+
+    if let Some(year) = year {
+        println!("{}Advent of Code {}{}", ANSI_BOLD, year, ANSI_RESET);
+    }
+    let mut stars = 0;
+    println!("| Day | Solution | Input | Part 1 | Part 2 |");
+    println!("| :---: | :---: | :---: | :---: | :---: |");
+    for day in 1..=25 {
+        let scaffolded = years::get(year, day).is_some();
+        // scaffolding creates an empty input file.
+        let has_input =
+            fs::metadata(year_file_path(year, "inputs", day)).is_ok_and(|meta| meta.len() > 0);
+        let locked = [answers.has_part(day, 1), answers.has_part(day, 2)];
+        if !scaffolded && !has_input && locked == [false, false] {
+            continue;
+        }
+        stars += locked.iter().filter(|locked| **locked).count();
+        println!(
+            "| {:02} | {} | {} | {} | {} |",
+            day,
+            mark(scaffolded, "✔"),
+            mark(has_input, "✔"),
+            mark(locked[0], "★"),
+            mark(locked[1], "★")
+        );
+    }
+    println!(
+        "{}Stars:{} {}/50 (parts with locked answers)",
+        ANSI_BOLD, ANSI_RESET, stars
+    );
+    Ok(())
+}
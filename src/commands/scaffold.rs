@@ -0,0 +1,326 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use super::{day_arg, finish, CommandError, CommandResult};
+use advent_of_code::{bin_name, config, module_path, root_dir, separate_year};
+use std::{
+    fs::{self, File, OpenOptions},
+    io::Write,
+};
+
+const MODULE_TEMPLATE: &str = r###"// `parse` runs once and both parts receive its result. Use `solution!(DAY, parse?)` if it returns
+// a `Result`, or drop it and use `solution!(DAY)` to pass the raw input to the parts instead.
+pub fn parse(input: &str) -> Vec<&str> {
+    input.lines().collect()
+}
+
+// Parts may return any type that converts into `crate::Answer`: integers such as `u64` or `i64`,
+// a `String`, or multi-line text for puzzles that draw their answer. Wrap it in an `Option`, or
+// in a `crate::Result` to report invalid input as an error.
+// Print with `crate::debug!` instead of `println!`, its output is shown with `--verbose`.
+pub fn part_one(lines: &[&str]) -> Option<u32> {
+    None
+}
+
+pub fn part_two(lines: &[&str]) -> Option<u32> {
+    None
+}
+
+crate::solution!(DAY, parse);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let example = crate::example::read(None, DAY).unwrap();
+        assert_eq!(part_one(&parse(&example.input)), example.answer(1));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let example = crate::example::read(None, DAY).unwrap();
+        assert_eq!(part_two(&parse(&example.input)), example.answer(2));
+    }
+}
+"###;
+
+const BIN_TEMPLATE: &str = r###"fn main() {
+    advent_of_code::runner::run(&advent_of_code::MODULE::Day);
+}
+"###;
+
+const REGISTRY_HEADER: &str = r###"/*
+ * This file is generated by `cargo scaffold` from the modules in this folder.
+ * Manual changes are overwritten the next time a day is scaffolded.
+ */
+use crate::Solution;
+"###;
+
+const REGISTRY_FOOTER: &str = r###"
+pub fn get(day: u8) -> Option<&'static dyn Solution> {
+    ALL.iter().copied().find(|solution| solution.day() == day)
+}
+"###;
+
+const YEARS_HEADER: &str = r###"/*
+ * This file is generated by `cargo scaffold` from the year folders next to it.
+ * Manual changes are overwritten the next time a day of a separate year is scaffolded.
+ */
+use crate::Solution;
+"###;
+
+const YEARS_FOOTER: &str = r###"
+/// The registered days of `year`, or of the main year for `None`.
+pub fn days(year: Option<i16>) -> &'static [&'static dyn Solution] {
+    match year {
+        None => crate::days::ALL,
+        Some(year) => YEARS
+            .iter()
+            .find(|(registered, _)| *registered == year)
+            .map_or(&[], |(_, days)| days),
+    }
+}
+
+pub fn get(year: Option<i16>, day: u8) -> Option<&'static dyn Solution> {
+    days(year)
+        .iter()
+        .copied()
+        .find(|solution| solution.day() == day)
+}
+"###;
+
+/// Creates a new file. Paths in this module are relative to the repository root, see
+/// [`root_dir`], so scaffolding works from any folder of the repository.
+fn safe_create_file(path: &str) -> Result<File, std::io::Error> {
+    OpenOptions::new()
+        .write(true)
+        .create_new(true)
+        .open(root_dir().join(path))
+}
+
+/// Creates a file and its folder, keeping the file if it exists.
+fn create_file(path: &str) -> Result<File, std::io::Error> {
+    let path = root_dir().join(path);
+    // the input and example folders can be moved in `.aoc.toml`.
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    OpenOptions::new()
+        .write(true)
+        .create(true)
+        .truncate(false)
+        .open(&path)
+}
+
+/// Module names of the entries of `dir` that are named `<prefix><digits><suffix>`, e.g. `day07`
+/// for `day07.rs`.
+fn modules(dir: &str, prefix: &str, digits: usize, suffix: &str) -> std::io::Result<Vec<String>> {
+    let mut modules: Vec<String> = fs::read_dir(root_dir().join(dir))?
+        .filter_map(|entry| {
+            let name = entry.ok()?.file_name().into_string().ok()?;
+            let module = name.strip_suffix(suffix)?;
+            let number = module.strip_prefix(prefix)?;
+            if number.len() == digits && number.chars().all(|c| c.is_ascii_digit()) {
+                Some(module.to_string())
+            } else {
+                None
+            }
+        })
+        .collect();
+    modules.sort();
+    Ok(modules)
+}
+
+/// Rebuilds the `mod.rs` of a folder of days, `src/days/` or `src/years/yYYYY/`, so that it
+/// registers every `dayNN.rs` module in it.
+fn write_registry(dir: &str) -> Result<(), std::io::Error> {
+    let modules = modules(dir, "day", 2, ".rs")?;
+
+    let mut contents = String::from(REGISTRY_HEADER);
+    contents.push('\n');
+    for module in &modules {
+        contents.push_str(&format!("pub mod {};\n", module));
+    }
+    contents.push_str("\n/// All registered days, in ascending order.\n");
+    contents.push_str("pub const ALL: &[&dyn Solution] = &[\n");
+    for module in &modules {
+        contents.push_str(&format!("    &{}::Day,\n", module));
+    }
+    contents.push_str("];\n");
+    contents.push_str(REGISTRY_FOOTER);
+
+    fs::write(root_dir().join(dir).join("mod.rs"), contents)
+}
+
+/// Rebuilds `src/years/mod.rs` so that it registers every `yYYYY` folder in `src/years/`.
+fn write_years_registry() -> Result<(), std::io::Error> {
+    let modules = modules("src/years", "y", 4, "")?;
+
+    let mut contents = String::from(YEARS_HEADER);
+    contents.push('\n');
+    for module in &modules {
+        contents.push_str(&format!("pub mod {};\n", module));
+    }
+    if !modules.is_empty() {
+        contents.push('\n');
+    }
+    contents.push_str(
+        "/// The registered days of every separate year, see `advent_of_code::separate_year`.\n",
+    );
+    if modules.is_empty() {
+        contents.push_str("pub const YEARS: &[(i16, &[&dyn Solution])] = &[];\n");
+    } else {
+        contents.push_str("pub const YEARS: &[(i16, &[&dyn Solution])] = &[\n");
+        for module in &modules {
+            contents.push_str(&format!("    ({}, {}::ALL),\n", &module[1..], module));
+        }
+        contents.push_str("];\n");
+    }
+    contents.push_str(YEARS_FOOTER);
+
+    fs::write(root_dir().join("src/years/mod.rs"), contents)
+}
+
+/// `aoc scaffold <day>`: creates the module, binary, input and example files of a day.
+/// With `--year <year>`, the day is added to a separate year, see
+/// [`advent_of_code::separate_year`].
+pub fn run(mut args: pico_args::Arguments) -> CommandResult {
+    let year = separate_year(args.opt_value_from_str(["-y", "--year"])?);
+    let day = day_arg(&mut args)?;
+    finish(args)?;
+
+    let day_padded = format!("{:02}", day);
+    let bin_name = bin_name(year, day);
+    let module = module_path(year, day);
+
+    let config = config::get();
+    let mut input_dir = config.inputs_dir.clone();
+    let mut example_dir = config.examples_dir.clone();
+    if let Some(year) = year {
+        input_dir.push(year.to_string());
+        example_dir.push(year.to_string());
+    }
+    let input_path = input_dir.join(format!("{}.txt", day_padded));
+    let input_path = input_path.display().to_string();
+    let example_path = example_dir.join(format!("{}.txt", day_padded));
+    let example_path = example_path.display().to_string();
+    let registry_dir = match year {
+        Some(year) => format!("src/years/y{}", year),
+        None => "src/days".to_string(),
+    };
+    let module_path = format!("{}/day{}.rs", registry_dir, day_padded);
+    let bin_path = format!("src/bin/{}.rs", bin_name);
+
+    if let Err(e) = fs::create_dir_all(root_dir().join(&registry_dir)) {
+        return Err(CommandError::failed(format!(
+            "Failed to create module folder: {}",
+            e
+        )));
+    }
+
+    let mut file = match safe_create_file(&module_path) {
+        Ok(file) => file,
+        Err(e) => {
+            return Err(CommandError::failed(format!(
+                "Failed to create module file: {}",
+                e
+            )));
+        }
+    };
+
+    let mut template = MODULE_TEMPLATE.to_string();
+    if let Some(year) = year {
+        template = template
+            .replace(
+                "crate::solution!(DAY, parse);",
+                &format!("crate::solution!(DAY, parse, year = {});", year),
+            )
+            .replace(
+                "crate::example::read(None, DAY)",
+                &format!("crate::example::read(Some({}), DAY)", year),
+            );
+    }
+    match file.write_all(template.replace("DAY", &day.to_string()).as_bytes()) {
+        Ok(_) => {
+            println!("Created module file \"{}\"", &module_path);
+        }
+        Err(e) => {
+            return Err(CommandError::failed(format!(
+                "Failed to write module contents: {}",
+                e
+            )));
+        }
+    }
+
+    let mut file = match safe_create_file(&bin_path) {
+        Ok(file) => file,
+        Err(e) => {
+            return Err(CommandError::failed(format!(
+                "Failed to create binary file: {}",
+                e
+            )));
+        }
+    };
+
+    match file.write_all(BIN_TEMPLATE.replace("MODULE", &module).as_bytes()) {
+        Ok(_) => {
+            println!("Created binary file \"{}\"", &bin_path);
+        }
+        Err(e) => {
+            return Err(CommandError::failed(format!(
+                "Failed to write binary contents: {}",
+                e
+            )));
+        }
+    }
+
+    let registered = write_registry(&registry_dir).and_then(|_| match year {
+        Some(_) => write_years_registry(),
+        None => Ok(()),
+    });
+    match registered {
+        Ok(_) => {
+            println!(
+                "Registered day {} in \"{}/mod.rs\"",
+                &day_padded, &registry_dir
+            );
+        }
+        Err(e) => {
+            return Err(CommandError::failed(format!(
+                "Failed to update registry: {}",
+                e
+            )));
+        }
+    }
+
+    match create_file(&input_path) {
+        Ok(_) => {
+            println!("Created empty input file \"{}\"", &input_path);
+        }
+        Err(e) => {
+            return Err(CommandError::failed(format!(
+                "Failed to create input file: {}",
+                e
+            )));
+        }
+    }
+
+    match create_file(&example_path) {
+        Ok(_) => {
+            println!("Created empty example file \"{}\"", &example_path);
+        }
+        Err(e) => {
+            return Err(CommandError::failed(format!(
+                "Failed to create example file: {}",
+                e
+            )));
+        }
+    }
+
+    println!("---");
+    println!("🎄 Type `cargo solve {}` to run your solution.", &bin_name);
+    Ok(())
+}
@@ -0,0 +1,25 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use super::{day_arg, CommandError, CommandResult};
+use advent_of_code::{runner, separate_year, years};
+
+/// `aoc solve <day>`: runs a registered day in this process. Takes the same flags as
+/// `cargo solve <day>`, and `--year <year>` for a day of a separate year.
+pub fn run(mut args: pico_args::Arguments) -> CommandResult {
+    let year = separate_year(args.opt_value_from_str(["-y", "--year"])?);
+    let day = day_arg(&mut args)?;
+    let solution = years::get(year, day).ok_or_else(|| {
+        let scaffold = match year {
+            Some(year) => format!("{} --year {}", day, year),
+            None => day.to_string(),
+        };
+        CommandError::failed(format!(
+            "Day {:02} is not scaffolded yet, create it with `cargo scaffold {}`.",
+            day, scaffold
+        ))
+    })?;
+    runner::run_with_args(solution, args);
+    Ok(())
+}
@@ -0,0 +1,99 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use super::{aoc_cli, day_arg, finish, CommandError, CommandResult};
+use advent_of_code::answers::Verdict;
+use advent_of_code::report::Status;
+use advent_of_code::runner::{self, RunOptions};
+use advent_of_code::{config, separate_year, years};
+
+fn parse_part(value: &str) -> Result<u8, String> {
+    match value {
+        "1" => Ok(1),
+        "2" => Ok(2),
+        _ => Err(format!("invalid part \"{}\", expected 1 or 2", value)),
+    }
+}
+
+/// `aoc submit <day> <part>`: solves a part against the puzzle input and submits its answer
+/// with aoc-cli.
+pub fn run(mut args: pico_args::Arguments) -> CommandResult {
+    let year: Option<i16> = args.opt_value_from_str(["-y", "--year"])?;
+    let day = day_arg(&mut args)?;
+    let part = args.free_from_fn(parse_part)?;
+    finish(args)?;
+
+    let solution = years::get(separate_year(year), day)
+        .ok_or_else(|| CommandError::failed(format!("Day {:02} is not scaffolded yet.", day)))?;
+    let year = year.or(config::get().year);
+    let mut options = RunOptions::default();
+    options.part = Some(part);
+    options.answers = runner::load_answers(solution.year());
+    let input = options
+        .input
+        .read(solution.year(), day)
+        .map_err(|e| CommandError::failed(e.to_string()))?;
+
+    let records = solution.run(&input, &options);
+    let record = records
+        .iter()
+        .find(|record| record.part == part && record.status == Status::Solved)
+        .ok_or_else(|| {
+            CommandError::failed(format!(
+                "Part {} of day {:02} has no answer to submit.",
+                part, day
+            ))
+        })?;
+    let answer = record.answer.clone().unwrap_or_default();
+    if answer.contains('\n') {
+        return Err(CommandError::failed(
+            "The answer spans several lines, read it from the output and submit it on the website.",
+        ));
+    }
+    match &record.verdict {
+        Some(Verdict::Correct) => {
+            println!("---");
+            println!("This answer is locked as correct already, not submitting it again.");
+            return Ok(());
+        }
+        Some(Verdict::Wrong { expected }) => {
+            return Err(CommandError::failed(format!(
+                "The answer differs from the locked answer {}, not submitting it.",
+                expected
+            )));
+        }
+        _ => {}
+    }
+
+    let mut cmd = aoc_cli()?;
+    let mut cmd_args = vec![];
+    if let Some(year) = year {
+        cmd_args.push("--year".into());
+        cmd_args.push(year.to_string());
+    }
+    cmd_args.extend([
+        "--day".into(),
+        day.to_string(),
+        "submit".into(),
+        part.to_string(),
+        answer,
+    ]);
+
+    println!("---");
+    println!("Submitting answer with >aoc {}", cmd_args.join(" "));
+    let status = cmd
+        .args(cmd_args)
+        .status()
+        .map_err(|e| CommandError::failed(format!("failed to spawn aoc-cli: {}", e)))?;
+    if !status.success() {
+        return Err(CommandError::Reported);
+    }
+
+    println!("---");
+    println!(
+        "🎄 If the answer was accepted, lock it with `cargo solve {} -- --lock`.",
+        advent_of_code::bin_name(solution.year(), day)
+    );
+    Ok(())
+}
@@ -0,0 +1,34 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use super::{finish, CommandError, CommandResult};
+use advent_of_code::{module_path, runner, separate_year};
+
+/// `aoc test [<day>]`: runs the tests of one day, including its examples, or the whole test
+/// suite if no day is given. With `--year <year>`, only the days of that separate year are
+/// tested.
+pub fn run(mut args: pico_args::Arguments) -> CommandResult {
+    let year = separate_year(args.opt_value_from_str(["-y", "--year"])?);
+    let day = args.opt_free_from_fn(runner::parse_day)?;
+    finish(args)?;
+
+    let mut cmd = runner::cargo();
+    cmd.arg("test");
+    match (year, day) {
+        (year, Some(day)) => {
+            cmd.args(["--lib", &format!("{}::", module_path(year, day))]);
+        }
+        (Some(year), None) => {
+            cmd.args(["--lib", &format!("years::y{}::", year)]);
+        }
+        (None, None) => {}
+    }
+    let status = cmd
+        .status()
+        .map_err(|e| CommandError::failed(format!("could not run cargo test: {}", e)))?;
+    if !status.success() {
+        return Err(CommandError::Reported);
+    }
+    Ok(())
+}
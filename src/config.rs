@@ -0,0 +1,414 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use crate::bench::BenchOptions;
+use std::io::{self, IsTerminal};
+use std::path::PathBuf;
+use std::str::FromStr;
+use std::sync::OnceLock;
+use std::time::Duration;
+use std::{env, fs, process};
+
+/// Optional file in the repository root holding project settings. Flags given on the command
+/// line take precedence over it.
+pub const CONFIG_FILE: &str = ".aoc.toml";
+
+/// Passes the colour choice on to child processes, e.g. to day binaries whose output is captured
+/// by `cargo all`. Overrides the `color` setting.
+pub const COLOR_ENV: &str = "AOC_COLOR";
+
+/// When output is styled with ANSI escape codes.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ColorChoice {
+    Always,
+    /// Only if stdout is a terminal and `NO_COLOR` is not set.
+    #[default]
+    Auto,
+    Never,
+}
+
+impl ColorChoice {
+    fn parse(value: &str) -> Option<ColorChoice> {
+        match value {
+            "always" => Some(ColorChoice::Always),
+            "auto" => Some(ColorChoice::Auto),
+            "never" => Some(ColorChoice::Never),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            ColorChoice::Always => "always",
+            ColorChoice::Auto => "auto",
+            ColorChoice::Never => "never",
+        }
+    }
+}
+
+impl FromStr for ColorChoice {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<ColorChoice, String> {
+        ColorChoice::parse(value)
+            .ok_or_else(|| format!("expected always, auto or never, found \"{}\"", value))
+    }
+}
+
+/// The settings in [`CONFIG_FILE`]. Settings that are left out keep their defaults.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Config {
+    /// `year`: the main year, see [`separate_year`](crate::separate_year). Also the puzzle year
+    /// for `download` and `submit`, aoc-cli picks the current event if unset.
+    pub year: Option<i16>,
+    /// `[paths] inputs`: folder of the puzzle inputs, relative to the repository root.
+    pub inputs_dir: PathBuf,
+    /// `[paths] examples`: folder of the example files, relative to the repository root.
+    pub examples_dir: PathBuf,
+    /// `[build] profile`: cargo profile used when the runner builds day binaries itself.
+    /// Defaults to the profile of the running binary.
+    pub profile: Option<String>,
+    /// `[output] color`: `always`, `auto` or `never`.
+    pub color: ColorChoice,
+    /// `[bench] warmup`, `iterations` and `time_budget_ms`: defaults for `--bench`.
+    pub bench: BenchOptions,
+    /// `[runner] timeout`: default for `cargo all -- --timeout`, in seconds.
+    pub timeout: Option<Duration>,
+    /// `[runner] part_timeout`: default for `--part-timeout`, in seconds.
+    pub part_timeout: Option<Duration>,
+    /// `[runner] jobs`: default for `cargo all -- --jobs`.
+    pub jobs: Option<usize>,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Config {
+            year: None,
+            inputs_dir: PathBuf::from("src").join("inputs"),
+            examples_dir: PathBuf::from("src").join("examples"),
+            profile: None,
+            color: ColorChoice::default(),
+            bench: BenchOptions::default(),
+            timeout: None,
+            part_timeout: None,
+            jobs: None,
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+enum Value {
+    String(String),
+    Integer(i64),
+    Float(f64),
+    Boolean(bool),
+}
+
+impl Value {
+    fn parse(value: &str) -> Option<Value> {
+        if let Some(quoted) = value.strip_prefix('"') {
+            let mut string = String::new();
+            let mut chars = quoted.chars();
+            loop {
+                match chars.next()? {
+                    '"' => break,
+                    '\\' => match chars.next()? {
+                        'n' => string.push('\n'),
+                        't' => string.push('\t'),
+                        c @ ('"' | '\\') => string.push(c),
+                        _ => return None,
+                    },
+                    c => string.push(c),
+                }
+            }
+            // only a comment may follow the closing quote.
+            let rest = chars.as_str().trim();
+            return (rest.is_empty() || rest.starts_with('#')).then_some(Value::String(string));
+        }
+
+        let value = match value.split_once('#') {
+            Some((value, _)) => value.trim(),
+            None => value,
+        };
+        let number = value.replace('_', "");
+        match value {
+            "true" => Some(Value::Boolean(true)),
+            "false" => Some(Value::Boolean(false)),
+            _ => number
+                .parse()
+                .map(Value::Integer)
+                .or_else(|_| number.parse().map(Value::Float))
+                .ok(),
+        }
+    }
+
+    fn describe(&self) -> &'static str {
+        match self {
+            Value::String(_) => "a string",
+            Value::Integer(_) => "an integer",
+            Value::Float(_) => "a number",
+            Value::Boolean(_) => "a boolean",
+        }
+    }
+}
+
+fn seconds(value: &Value) -> Option<Duration> {
+    match value {
+        Value::Integer(n) => u64::try_from(*n).ok().map(Duration::from_secs),
+        Value::Float(n) => Duration::try_from_secs_f64(*n).ok(),
+        _ => None,
+    }
+}
+
+fn positive<T: TryFrom<i64>>(value: &Value) -> Option<T> {
+    match value {
+        Value::Integer(n) if *n > 0 => T::try_from(*n).ok(),
+        _ => None,
+    }
+}
+
+impl Config {
+    /// Parses the contents of a config file. This is the subset of TOML the settings need:
+    /// `[section]` headers, `key = value` pairs of strings, numbers and booleans, and comments.
+    pub fn parse(contents: &str) -> Result<Config, String> {
+        let mut config = Config::default();
+        let mut section = String::new();
+
+        for (index, line) in contents.lines().enumerate() {
+            let line = line.trim();
+            let error = |message: String| format!("line {}: {}", index + 1, message);
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            if let Some(header) = line.strip_prefix('[') {
+                let (name, rest) = header
+                    .split_once(']')
+                    .ok_or_else(|| error(format!("invalid section header \"{}\"", line)))?;
+                if !rest.trim().is_empty() && !rest.trim().starts_with('#') {
+                    return Err(error(format!("invalid section header \"{}\"", line)));
+                }
+                section = name.trim().to_string();
+                continue;
+            }
+
+            let (key, value) = line
+                .split_once('=')
+                .ok_or_else(|| error(format!("expected `key = value`, found \"{}\"", line)))?;
+            let key = key.trim();
+            let value = Value::parse(value.trim())
+                .ok_or_else(|| error(format!("invalid value for \"{}\"", key)))?;
+            config.set(&section, key, &value).map_err(error)?;
+        }
+        Ok(config)
+    }
+
+    fn set(&mut self, section: &str, key: &str, value: &Value) -> Result<(), String> {
+        let invalid = |expected: &str| {
+            format!(
+                "\"{}\" must be {}, found {}",
+                key,
+                expected,
+                value.describe()
+            )
+        };
+        let string = || match value {
+            Value::String(s) => Ok(s.clone()),
+            _ => Err(invalid("a string")),
+        };
+
+        match (section, key) {
+            ("", "year") => {
+                self.year = Some(positive(value).ok_or_else(|| invalid("a year"))?);
+            }
+            ("paths", "inputs") => self.inputs_dir = string()?.into(),
+            ("paths", "examples") => self.examples_dir = string()?.into(),
+            ("build", "profile") => self.profile = Some(string()?),
+            ("output", "color") => {
+                let color = string()?;
+                self.color = ColorChoice::parse(&color).ok_or_else(|| {
+                    format!(
+                        "\"color\" must be \"always\", \"auto\" or \"never\", found \"{}\"",
+                        color
+                    )
+                })?;
+            }
+            ("bench", "warmup") => {
+                self.bench.warmup = match value {
+                    Value::Integer(n) => u32::try_from(*n).ok(),
+                    _ => None,
+                }
+                .ok_or_else(|| invalid("a count"))?;
+            }
+            ("bench", "iterations") => {
+                self.bench.iterations = Some(positive(value).ok_or_else(|| invalid("a count"))?);
+            }
+            ("bench", "time_budget_ms") => {
+                let millis: u64 = positive(value).ok_or_else(|| invalid("milliseconds"))?;
+                self.bench.time_budget = Duration::from_millis(millis);
+            }
+            ("runner", "timeout") => {
+                self.timeout = Some(seconds(value).ok_or_else(|| invalid("seconds"))?);
+            }
+            ("runner", "part_timeout") => {
+                self.part_timeout = Some(seconds(value).ok_or_else(|| invalid("seconds"))?);
+            }
+            ("runner", "jobs") => {
+                self.jobs = Some(positive(value).ok_or_else(|| invalid("a count"))?);
+            }
+            ("", key) => return Err(format!("unknown setting \"{}\"", key)),
+            (section, key) => return Err(format!("unknown setting \"{}.{}\"", section, key)),
+        }
+        Ok(())
+    }
+
+    pub fn path() -> PathBuf {
+        crate::root_dir().join(CONFIG_FILE)
+    }
+
+    /// Loads the config file. A missing file is treated as an empty one.
+    pub fn load() -> Result<Config, String> {
+        match fs::read_to_string(Self::path()) {
+            Ok(contents) => Config::parse(&contents),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Config::default()),
+            Err(e) => Err(e.to_string()),
+        }
+    }
+}
+
+/// The project settings, loaded on first use. Exits if the config file is invalid.
+pub fn get() -> &'static Config {
+    static CONFIG: OnceLock<Config> = OnceLock::new();
+    CONFIG.get_or_init(|| match Config::load() {
+        Ok(config) => config,
+        Err(e) => {
+            eprintln!("could not read {}: {}", CONFIG_FILE, e);
+            process::exit(1);
+        }
+    })
+}
+
+/// Set by `--color`, see [`color_from_args`].
+static COLOR_FLAG: OnceLock<ColorChoice> = OnceLock::new();
+
+/// Takes `--color <always|auto|never>` from `args`. It takes precedence over the `color`
+/// setting, and has to be read before anything is printed.
+pub fn color_from_args(args: &mut pico_args::Arguments) -> Result<(), pico_args::Error> {
+    if let Some(choice) = args.opt_value_from_str("--color")? {
+        #[allow(unused_must_use)]
+        {
+            COLOR_FLAG.set(choice);
+        }
+    }
+    Ok(())
+}
+
+/// Whether output is styled, following `--color`, [`COLOR_ENV`] or the `color` setting.
+pub fn color_enabled() -> bool {
+    static ENABLED: OnceLock<bool> = OnceLock::new();
+    *ENABLED.get_or_init(|| {
+        let choice = COLOR_FLAG.get().copied().unwrap_or_else(|| {
+            env::var(COLOR_ENV)
+                .ok()
+                .and_then(|value| ColorChoice::parse(&value))
+                .unwrap_or(get().color)
+        });
+        match choice {
+            ColorChoice::Always => true,
+            ColorChoice::Auto => io::stdout().is_terminal() && env::var_os("NO_COLOR").is_none(),
+            ColorChoice::Never => false,
+        }
+    })
+}
+
+/// The [`COLOR_ENV`] value that makes a child process style its output like this one, even
+/// if its output is captured.
+pub fn child_color() -> &'static str {
+    match color_enabled() {
+        true => ColorChoice::Always.as_str(),
+        false => ColorChoice::Never.as_str(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_config() {
+        let config = Config::parse(
+            r#"
+# settings
+year = 2022
+
+[paths]
+inputs = "puzzles/inputs" # a comment
+examples = "puzzles/examples"
+
+[build]
+profile = "release"
+
+[output]
+color = "never"
+
+[bench]
+warmup = 0
+iterations = 1_000
+time_budget_ms = 500
+
+[runner]
+timeout = 10
+part_timeout = 2.5
+jobs = 4
+"#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            config,
+            Config {
+                year: Some(2022),
+                inputs_dir: "puzzles/inputs".into(),
+                examples_dir: "puzzles/examples".into(),
+                profile: Some("release".into()),
+                color: ColorChoice::Never,
+                bench: BenchOptions {
+                    warmup: 0,
+                    iterations: Some(1000),
+                    time_budget: Duration::from_millis(500),
+                },
+                timeout: Some(Duration::from_secs(10)),
+                part_timeout: Some(Duration::from_millis(2500)),
+                jobs: Some(4),
+            }
+        );
+        assert_eq!(Config::parse("").unwrap(), Config::default());
+    }
+
+    #[test]
+    fn test_parse_config_errors() {
+        let error = |contents: &str| Config::parse(contents).unwrap_err();
+
+        assert_eq!(error("yeer = 2022"), "line 1: unknown setting \"yeer\"");
+        assert_eq!(
+            error("[bench]\nwarmup = \"3\""),
+            "line 2: \"warmup\" must be a count, found a string"
+        );
+        assert_eq!(
+            error("[output]\ncolor = \"blue\""),
+            "line 2: \"color\" must be \"always\", \"auto\" or \"never\", found \"blue\""
+        );
+        assert_eq!(
+            error("[paths]\ninputs = \"src"),
+            "line 2: invalid value for \"inputs\""
+        );
+        assert_eq!(
+            error("[runner"),
+            "line 1: invalid section header \"[runner\""
+        );
+        assert_eq!(
+            error("jobs"),
+            "line 1: expected `key = value`, found \"jobs\""
+        );
+    }
+}
@@ -0,0 +1,72 @@
+use crate::helpers::get_all_lines;
+
+pub fn parse(input: &str) -> Vec<u32> {
+    let lines = get_all_lines(input);
+    let elves = get_elves_snacks(lines);
+    get_elves_total_calories(elves)
+}
+
+pub fn part_one(total_calories: &[u32]) -> Option<u32> {
+    total_calories.iter().max().copied()
+}
+
+pub fn part_two(total_calories: &[u32]) -> Option<u32> {
+    // just sort them and return the top 3 values
+    let mut total_calories: Vec<u32> = total_calories.to_vec();
+    total_calories.sort();
+    total_calories.reverse();
+    total_calories.truncate(3);
+    let top_3: u32 = total_calories.iter().sum();
+    Some(top_3)
+}
+
+fn get_elves_snacks(lines: Vec<&str>) -> Vec<Vec<&str>> {
+    // make a new vector of vectors by splitting array on empty string
+    let mut groups: Vec<Vec<&str>> = Vec::new();
+    let mut group: Vec<&str> = Vec::new();
+    for line in lines {
+        if line.is_empty() {
+            groups.push(group);
+            group = Vec::new();
+        } else {
+            group.push(line);
+        }
+    }
+    groups
+}
+
+fn get_elves_total_calories(elves: Vec<Vec<&str>>) -> Vec<u32> {
+    // find the sum of each vector in `elves`
+    let mut total_calories: Vec<u32> = Vec::new();
+    for elf in elves {
+        let mut sum = 0;
+        for line in elf {
+            sum += line.parse::<u32>().unwrap();
+        }
+        total_calories.push(sum)
+    }
+    total_calories
+}
+
+crate::solution!(1, parse);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let example = crate::example::read(None, 1).unwrap();
+        let got = part_one(&parse(&example.input));
+        let want = example.answer(1);
+        assert_eq!(got, want);
+    }
+
+    #[test]
+    fn test_part_two() {
+        let example = crate::example::read(None, 1).unwrap();
+        let got = part_two(&parse(&example.input));
+        let want = example.answer(2);
+        assert_eq!(got, want);
+    }
+}
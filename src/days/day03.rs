@@ -0,0 +1,104 @@
+use crate::helpers::get_lines_without_empty;
+use crate::{Error, Result};
+
+fn get_same_letters_in_line(lines: &[&str]) -> Vec<char> {
+    let mut result: Vec<char> = Vec::new();
+    lines.iter().copied().for_each(|line| {
+        let half = line.len() / 2;
+        let chars: Vec<char> = line.chars().collect();
+        let (first, second) = chars.split_at(half);
+
+        for c in first.iter() {
+            if second.contains(c) {
+                result.push(*c);
+                break;
+            }
+        }
+    });
+    result
+}
+
+fn get_same_letter_in_lines(lines: Vec<&str>) -> Result<char> {
+    match lines.as_slice() {
+        [first, second, third] => {
+            let first_chars: Vec<char> = first.chars().collect();
+            let second_chars: Vec<char> = second.chars().collect();
+            let third_chars: Vec<char> = third.chars().collect();
+
+            for c in first_chars.iter() {
+                if second_chars.contains(c) && third_chars.contains(c) {
+                    return Ok(c.to_owned());
+                }
+            }
+        }
+        _ => {
+            return Err(Error::invalid_input(format!(
+                "unexpected number of lines in group: {}",
+                lines.len()
+            )))
+        }
+    }
+    Err(Error::invalid_input(format!(
+        "no letter found for group {:?}",
+        lines
+    )))
+}
+
+fn get_points(chars: Vec<char>) -> u32 {
+    let mut result: u32 = 0;
+    chars.iter().for_each(|c| result += points_for_char(*c));
+    result
+}
+
+fn points_for_char(c: char) -> u32 {
+    if c.is_lowercase() {
+        c as u32 - 96
+    } else {
+        c as u32 - 38
+    }
+}
+
+pub fn parse(input: &str) -> Vec<&str> {
+    get_lines_without_empty(input)
+}
+
+pub fn part_one(lines: &[&str]) -> Option<u32> {
+    let same_letters = get_same_letters_in_line(lines);
+    Some(get_points(same_letters))
+}
+
+pub fn part_two(lines: &[&str]) -> Result<u32> {
+    let groups: Vec<&[&str]> = lines.chunks(3).collect();
+    groups
+        .iter()
+        .map(|group| get_same_letter_in_lines(group.to_vec()))
+        .try_fold(0, |acc, c| Ok(acc + points_for_char(c?)))
+}
+
+crate::solution!(3, parse);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_two_incomplete_group() {
+        let lines = [
+            "vJrwpWtwJgWrhcsFMMfFFhFp",
+            "jqHRNqRjqzjGDLGLrsFMfFZSrLrFZsSL",
+        ];
+        assert!(part_two(&lines).is_err());
+    }
+
+    #[test]
+    fn test_part_one() {
+        let example = crate::example::read(None, 3).unwrap();
+        assert_eq!(part_one(&parse(&example.input)), example.answer(1));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let example = crate::example::read(None, 3).unwrap();
+        assert_eq!(part_two(&parse(&example.input)).ok(), example.answer(2));
+    }
+}
@@ -0,0 +1,57 @@
+use crate::helpers::get_lines_without_empty;
+
+fn pairs(input: &str) -> Vec<&str> {
+    input.split(',').collect()
+}
+
+fn sequence(range: &str) -> Vec<u32> {
+    let mut range = range.split('-').map(|x| x.parse::<u32>().unwrap());
+    let start: u32 = range.next().unwrap();
+    let end: u32 = range.next().unwrap();
+
+    (start..end + 1).collect()
+}
+
+fn fully_contains(first: Vec<u32>, second: Vec<u32>) -> bool {
+    first.iter().all(|x: &u32| second.contains(x)) || second.iter().all(|x: &u32| first.contains(x))
+}
+
+pub fn parse(input: &str) -> Vec<Vec<&str>> {
+    let lines: Vec<&str> = get_lines_without_empty(input);
+    lines.iter().map(|line: &&str| pairs(line)).collect()
+}
+
+pub fn part_one(pairs: &[Vec<&str>]) -> Option<u32> {
+    let mut fully_contained_total = 0;
+    pairs.iter().for_each(|pair: &Vec<&str>| {
+        let first: Vec<u32> = sequence(pair[0]);
+        let second: Vec<u32> = sequence(pair[1]);
+        if fully_contains(first, second) {
+            fully_contained_total += 1;
+        }
+    });
+    Some(fully_contained_total)
+}
+
+pub fn part_two(_pairs: &[Vec<&str>]) -> Option<u32> {
+    None
+}
+
+crate::solution!(4, parse);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let example = crate::example::read(None, 4).unwrap();
+        assert_eq!(part_one(&parse(&example.input)), example.answer(1));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let example = crate::example::read(None, 4).unwrap();
+        assert_eq!(part_two(&parse(&example.input)), example.answer(2));
+    }
+}
@@ -0,0 +1,56 @@
+use std::collections::HashSet;
+
+use crate::helpers::get_lines_without_empty;
+
+fn uniques(beginning: usize, end: usize, chars: &[char]) -> HashSet<char> {
+    let set: HashSet<char> = HashSet::new();
+    chars[beginning..=end].iter().fold(set, |mut set, &c| {
+        set.insert(c);
+        set
+    })
+}
+
+fn first_unique_sequence(size: usize, line: &str) -> u32 {
+    let mut beginning: usize = 0;
+    let mut end: u32 = (size - 1) as u32;
+    let chars: Vec<char> = line.chars().collect();
+    while end <= line.len() as u32 {
+        beginning += 1;
+        end += 1;
+        if uniques(beginning, end as usize, &chars).len() == size {
+            break;
+        }
+    }
+    end + 1
+}
+
+pub fn parse(input: &str) -> &str {
+    get_lines_without_empty(input).first().unwrap()
+}
+
+pub fn part_one(line: &str) -> Option<u32> {
+    Some(first_unique_sequence(4, line))
+}
+
+pub fn part_two(line: &str) -> Option<u32> {
+    Some(first_unique_sequence(14, line))
+}
+
+crate::solution!(6, parse);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let example = crate::example::read(None, 6).unwrap();
+        assert_eq!(part_one(parse(&example.input)), example.answer(1));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let example = crate::example::read(None, 6).unwrap();
+        assert_eq!(part_two(parse(&example.input)), example.answer(2));
+    }
+}
@@ -0,0 +1,30 @@
+/*
+ * This file is generated by `cargo scaffold` from the modules in this folder.
+ * Manual changes are overwritten the next time a day is scaffolded.
+ */
+use crate::Solution;
+
+pub mod day01;
+pub mod day02;
+pub mod day03;
+pub mod day04;
+pub mod day05;
+pub mod day06;
+pub mod day07;
+pub mod day09;
+
+/// All registered days, in ascending order.
+pub const ALL: &[&dyn Solution] = &[
+    &day01::Day,
+    &day02::Day,
+    &day03::Day,
+    &day04::Day,
+    &day05::Day,
+    &day06::Day,
+    &day07::Day,
+    &day09::Day,
+];
+
+pub fn get(day: u8) -> Option<&'static dyn Solution> {
+    ALL.iter().copied().find(|solution| solution.day() == day)
+}
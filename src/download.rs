@@ -0,0 +1,106 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::path::{Path, PathBuf};
+
+/// Responses of the website that aoc-cli may save in place of an input, and what they mean.
+const ERROR_PAGES: &[(&str, &str)] = &[
+    (
+        "Please don't repeatedly request this endpoint before it unlocks",
+        "the puzzle is not unlocked yet",
+    ),
+    (
+        "Please log in to get your puzzle input",
+        "not logged in, the session cookie is missing or expired",
+    ),
+    ("404 Not Found", "the puzzle does not exist"),
+    ("Internal Server Error", "the website reported an error"),
+];
+
+/// Markup that no puzzle input contains, but every web page does.
+const HTML_TAGS: &[&str] = &["<!doctype html", "<html", "</html>", "<body", "</body>"];
+
+/// Checks that a downloaded input looks like a puzzle input. Returns why it does not, e.g. if
+/// it is empty or an error page of the website. The whole download is checked, as a page can
+/// start with a line that looks like input.
+pub fn check(contents: &str) -> Result<(), &'static str> {
+    if contents.trim().is_empty() {
+        return Err("the download is empty");
+    }
+    if let Some((_, reason)) = ERROR_PAGES
+        .iter()
+        .find(|(message, _)| contents.contains(message))
+    {
+        return Err(reason);
+    }
+    let lowercase = contents.to_ascii_lowercase();
+    if HTML_TAGS.iter().any(|tag| lowercase.contains(tag)) {
+        return Err("the download is a web page");
+    }
+    Ok(())
+}
+
+/// Where the previous contents of an input are kept when it is replaced: `07.txt.bak`, or
+/// `07.txt.bak.<n>` for the `n`th backup, so earlier backups are never overwritten.
+pub fn backup_path(path: &Path, n: u32) -> PathBuf {
+    let mut backup = path.as_os_str().to_owned();
+    backup.push(".bak");
+    if n > 0 {
+        backup.push(format!(".{}", n));
+    }
+    backup.into()
+}
+
+/// The first [`backup_path`] of `path` that does not exist yet.
+pub fn next_backup_path(path: &Path) -> PathBuf {
+    (0..)
+        .map(|n| backup_path(path, n))
+        .find(|backup| !backup.exists())
+        .unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_check() {
+        assert_eq!(check("1000\n2000\n"), Ok(()));
+        assert_eq!(check(""), Err("the download is empty"));
+        assert_eq!(check(" \n\n"), Err("the download is empty"));
+        assert_eq!(
+            check("Please don't repeatedly request this endpoint before it unlocks! The calendar countdown is synchronized with the server time; the link will be enabled on the calendar the instant this puzzle becomes available.\n"),
+            Err("the puzzle is not unlocked yet")
+        );
+        assert_eq!(
+            check("Puzzle inputs differ by user.  Please log in to get your puzzle input.\n"),
+            Err("not logged in, the session cookie is missing or expired")
+        );
+        assert_eq!(
+            check("<!DOCTYPE html>\n<html lang=\"en-us\">"),
+            Err("the download is a web page")
+        );
+        assert_eq!(
+            check("1000\n<html lang=\"en-us\">\n<body>\n"),
+            Err("the download is a web page")
+        );
+        assert_eq!(
+            check("R 4\nPlease log in to get your puzzle input.\n"),
+            Err("not logged in, the session cookie is missing or expired")
+        );
+        assert_eq!(check("<{([{{}}[<[[[<>{}]]]>[]]\n"), Ok(()));
+    }
+
+    #[test]
+    fn test_backup_path() {
+        assert_eq!(
+            backup_path(Path::new("src/inputs/07.txt"), 0),
+            PathBuf::from("src/inputs/07.txt.bak")
+        );
+        assert_eq!(
+            backup_path(Path::new("src/inputs/07.txt"), 2),
+            PathBuf::from("src/inputs/07.txt.bak.2")
+        );
+    }
+}
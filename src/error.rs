@@ -0,0 +1,43 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::fmt::{self, Display};
+use std::path::PathBuf;
+use std::{error, io};
+
+pub type Result<T, E = Error> = std::result::Result<T, E>;
+
+#[derive(Debug)]
+pub enum Error {
+    /// An input or example file could not be read.
+    Read { path: PathBuf, source: io::Error },
+    /// A solution could not make sense of its input.
+    InvalidInput(String),
+}
+
+impl Error {
+    pub fn invalid_input(message: impl Into<String>) -> Error {
+        Error::InvalidInput(message.into())
+    }
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Read { path, source } => {
+                write!(f, "could not read \"{}\": {}", path.display(), source)
+            }
+            Error::InvalidInput(message) => write!(f, "invalid input: {}", message),
+        }
+    }
+}
+
+impl error::Error for Error {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            Error::Read { source, .. } => Some(source),
+            Error::InvalidInput(_) => None,
+        }
+    }
+}
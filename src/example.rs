@@ -0,0 +1,255 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use crate::report::{Status, PARSE_STEP};
+use crate::runner::RunOptions;
+use crate::{Error, Result, Solution};
+use std::fs;
+use std::path::PathBuf;
+use std::str::FromStr;
+
+/// One example file of a day: `src/examples/NN.txt` or `src/examples/NN-<n>.txt`.
+///
+/// An example can start with front-matter holding the answers the puzzle text gives for it:
+///
+/// ```text
+/// ---
+/// part_one: 13
+/// part_two: 1
+/// ---
+/// R 4
+/// U 4
+/// ```
+///
+/// Either answer can be left out, e.g. for an example that is only given for part 2.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Example {
+    pub path: PathBuf,
+    /// The example without its front-matter.
+    pub input: String,
+    pub part_one: Option<String>,
+    pub part_two: Option<String>,
+}
+
+impl Example {
+    pub fn parse(path: PathBuf, contents: &str) -> Result<Example> {
+        let (front_matter, input) = split_front_matter(contents);
+        let mut example = Example {
+            path,
+            input: input.to_string(),
+            part_one: None,
+            part_two: None,
+        };
+
+        for line in front_matter.unwrap_or_default().lines() {
+            if line.trim().is_empty() {
+                continue;
+            }
+            let (key, value) = line.split_once(':').unwrap_or((line, ""));
+            let value = Some(value.trim().to_string()).filter(|value| !value.is_empty());
+            match key.trim() {
+                "part_one" => example.part_one = value,
+                "part_two" => example.part_two = value,
+                other => {
+                    return Err(Error::invalid_input(format!(
+                        "unknown key \"{}\" in front-matter of \"{}\"",
+                        other,
+                        example.path.display()
+                    )))
+                }
+            }
+        }
+
+        Ok(example)
+    }
+
+    /// The answer this example expects for `part`, if the front-matter provides one.
+    pub fn expected(&self, part: u8) -> Option<&str> {
+        match part {
+            1 => self.part_one.as_deref(),
+            2 => self.part_two.as_deref(),
+            _ => None,
+        }
+    }
+
+    /// The answer this example expects for `part`, parsed into the type the part returns, so
+    /// unit tests can compare it directly. Panics if the answer does not parse.
+    pub fn answer<T: FromStr>(&self, part: u8) -> Option<T> {
+        self.expected(part).map(|answer| {
+            answer.parse().unwrap_or_else(|_| {
+                panic!(
+                    "cannot parse the part {} answer \"{}\" of \"{}\"",
+                    part,
+                    answer,
+                    self.path.display()
+                )
+            })
+        })
+    }
+}
+
+/// Splits `contents` into its front-matter, if any, and the example input.
+fn split_front_matter(contents: &str) -> (Option<&str>, &str) {
+    let rest = match contents
+        .strip_prefix("---\n")
+        .or_else(|| contents.strip_prefix("---\r\n"))
+    {
+        Some(rest) => rest,
+        None => return (None, contents),
+    };
+
+    let mut offset = 0;
+    for line in rest.split_inclusive('\n') {
+        if line.trim_end() == "---" {
+            return (Some(&rest[..offset]), &rest[offset + line.len()..]);
+        }
+        offset += line.len();
+    }
+
+    // an unterminated block is not front-matter, but part of the example.
+    (None, contents)
+}
+
+/// Returns `contents` without its front-matter.
+pub fn strip_front_matter(contents: &str) -> &str {
+    split_front_matter(contents).1
+}
+
+/// Reads the main example of a day, `NN.txt`, with its front-matter.
+pub fn read(year: Option<i16>, day: u8) -> Result<Example> {
+    let path = crate::year_file_path(year, "examples", day);
+    let contents = fs::read_to_string(&path).map_err(|source| Error::Read {
+        path: path.clone(),
+        source,
+    })?;
+    Example::parse(path, &contents)
+}
+
+/// Loads all examples of a day, `NN.txt` first, then `NN-<n>.txt` in order. Examples of a
+/// separate year are looked up in its own folder, see [`separate_year`](crate::separate_year).
+pub fn load(year: Option<i16>, day: u8) -> Result<Vec<Example>> {
+    let folder = crate::year_folder_path(year, "examples");
+    let prefix = format!("{:02}", day);
+
+    let entries = fs::read_dir(&folder).map_err(|source| Error::Read {
+        path: folder.clone(),
+        source,
+    })?;
+
+    let mut paths: Vec<(String, PathBuf)> = entries
+        .filter_map(|entry| {
+            let path = entry.ok()?.path();
+            let stem = path.file_stem()?.to_str()?;
+            let suffix = match stem.strip_prefix(&prefix)? {
+                "" => "",
+                suffix => suffix.strip_prefix('-')?,
+            };
+            let is_txt = path.extension().is_some_and(|ext| ext == "txt");
+            is_txt.then(|| (suffix.to_string(), path.clone()))
+        })
+        .collect();
+    // sorting by length first keeps `NN-2` before `NN-10`.
+    paths.sort_by(|(a, _), (b, _)| (a.len(), a).cmp(&(b.len(), b)));
+
+    paths
+        .into_iter()
+        .map(|(_, path)| {
+            let contents = fs::read_to_string(&path).map_err(|source| Error::Read {
+                path: path.clone(),
+                source,
+            })?;
+            Example::parse(path, &contents)
+        })
+        .collect()
+}
+
+/// Runs `solution` against each of its examples that has expected answers and panics with a
+/// list of all mismatches. The `solution!` macro generates a test that calls this.
+///
+/// Parts that are not solved yet are skipped, so answers can be filled in before solving.
+pub fn check(solution: &dyn Solution) {
+    let examples = load(solution.year(), solution.day()).unwrap_or_else(|e| panic!("{}", e));
+    let mut failures = vec![];
+
+    for example in &examples {
+        if example.part_one.is_none() && example.part_two.is_none() {
+            continue;
+        }
+
+        let name = example.path.file_name().unwrap().to_string_lossy();
+        let records = solution.run(&example.input, &RunOptions::default());
+
+        for record in records {
+            let expected = example.expected(record.part);
+            let error = record.error.as_deref().unwrap_or_default();
+            let failure = match (record.status, expected) {
+                (Status::Failed, _) if record.part == PARSE_STEP => {
+                    Some(format!("{}: parse failed: {}", name, error))
+                }
+                (Status::Failed, Some(expected)) => Some(format!(
+                    "{}, part {}: failed: {}, expected {}",
+                    name, record.part, error, expected
+                )),
+                (Status::Solved, Some(expected)) if record.answer.as_deref() != Some(expected) => {
+                    Some(format!(
+                        "{}, part {}: got {}, expected {}",
+                        name,
+                        record.part,
+                        record.answer.unwrap_or_default(),
+                        expected
+                    ))
+                }
+                _ => None,
+            };
+            failures.extend(failure);
+        }
+    }
+
+    assert!(
+        failures.is_empty(),
+        "examples do not match:\n{}",
+        failures.join("\n")
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_front_matter() {
+        let example = Example::parse(
+            "09-2.txt".into(),
+            "---\npart_one: 88\npart_two: 36\n---\nR 5\nU 8\n",
+        )
+        .unwrap();
+        assert_eq!(example.input, "R 5\nU 8\n");
+        assert_eq!(example.expected(1), Some("88"));
+        assert_eq!(example.expected(2), Some("36"));
+        assert_eq!(example.answer::<u32>(1), Some(88));
+    }
+
+    #[test]
+    fn test_parse_partial_front_matter() {
+        let example =
+            Example::parse("05.txt".into(), "---\npart_one: CMZ\npart_two:\n---\n[D]\n").unwrap();
+        assert_eq!(example.input, "[D]\n");
+        assert_eq!(example.expected(1), Some("CMZ"));
+        assert_eq!(example.expected(2), None);
+    }
+
+    #[test]
+    fn test_parse_without_front_matter() {
+        let example = Example::parse("01.txt".into(), "1000\n2000\n").unwrap();
+        assert_eq!(example.input, "1000\n2000\n");
+        assert_eq!(example.part_one, None);
+
+        assert_eq!(strip_front_matter("---\n1000\n"), "---\n1000\n");
+    }
+
+    #[test]
+    fn test_parse_unknown_key() {
+        assert!(Example::parse("01.txt".into(), "---\npart_1: 24000\n---\n1000\n").is_err());
+    }
+}
@@ -0,0 +1,294 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use crate::report::{self, PartRecord, Status, Value};
+use std::fmt::Write as _;
+use std::fs::{self, OpenOptions};
+use std::io::{self, Write};
+use std::path::PathBuf;
+use std::process::Command;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// Local file that `cargo all -- --bench --record` appends its timings to, relative to the
+/// repository root.
+pub const HISTORY_FILE: &str = "bench_history.jsonl";
+
+/// The median timing of one step of a day in a recorded benchmark run.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct HistoryEntry {
+    /// When the run was recorded, in nanoseconds since the Unix epoch. Identifies the run, also
+    /// if two runs are recorded within the same second. Files written before hold seconds, which
+    /// sort before every newer run.
+    pub run: u64,
+    /// Short hash of the commit that was benchmarked, with a `-dirty` suffix for
+    /// uncommitted changes.
+    pub commit: String,
+    /// Cargo profile the solutions were built with, e.g. `release`.
+    pub profile: String,
+    pub day: u8,
+    /// `0` for the parse step, see [`report::PARSE_STEP`].
+    pub part: u8,
+    pub median: Duration,
+}
+
+impl HistoryEntry {
+    /// Entries for all solved steps in `records`.
+    pub fn from_records(
+        run: u64,
+        commit: &str,
+        profile: &str,
+        records: &[PartRecord],
+    ) -> Vec<HistoryEntry> {
+        records
+            .iter()
+            .filter(|record| record.status == Status::Solved)
+            .map(|record| HistoryEntry {
+                run,
+                commit: commit.to_string(),
+                profile: profile.to_string(),
+                day: record.day,
+                part: record.part,
+                median: record.elapsed,
+            })
+            .collect()
+    }
+
+    pub fn to_json(&self) -> String {
+        format!(
+            "{{\"run\":{},\"commit\":{},\"profile\":{},\"day\":{},\"part\":{},\"median_ns\":{}}}",
+            self.run,
+            report::json_string(&self.commit),
+            report::json_string(&self.profile),
+            self.day,
+            self.part,
+            self.median.as_nanos()
+        )
+    }
+
+    pub fn from_json(line: &str) -> Option<HistoryEntry> {
+        let mut run = None;
+        let mut commit = None;
+        let mut profile = None;
+        let mut day = None;
+        let mut part = None;
+        let mut median = None;
+
+        for (key, value) in report::parse_object(line)? {
+            match (key.as_str(), value) {
+                ("run", Value::Number(n)) => run = u64::try_from(n).ok(),
+                ("commit", Value::String(s)) => commit = Some(s),
+                ("profile", Value::String(s)) => profile = Some(s),
+                ("day", Value::Number(n)) => day = u8::try_from(n).ok(),
+                ("part", Value::Number(n)) => part = u8::try_from(n).ok(),
+                ("median_ns", Value::Number(n)) => median = report::duration_from_nanos(n),
+                _ => return None,
+            }
+        }
+
+        Some(HistoryEntry {
+            run: run?,
+            commit: commit?,
+            profile: profile?,
+            day: day?,
+            part: part?,
+            median: median?,
+        })
+    }
+}
+
+/// Name of the history file of `year`: [`HISTORY_FILE`] for the main year,
+/// `bench_history-YYYY.jsonl` for a separate one, see [`separate_year`](crate::separate_year).
+pub fn file_name(year: Option<i16>) -> String {
+    match year {
+        None => HISTORY_FILE.to_string(),
+        Some(year) => format!("bench_history-{}.jsonl", year),
+    }
+}
+
+pub fn path(year: Option<i16>) -> PathBuf {
+    crate::root_dir().join(file_name(year))
+}
+
+/// Identifies a new run, see [`HistoryEntry::run`].
+pub fn run_id() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |elapsed| elapsed.as_nanos() as u64)
+}
+
+/// Short hash of the checked out commit, e.g. `5ab38b7-dirty`, or `unknown` outside of a git
+/// repository.
+pub fn current_commit() -> String {
+    let git = |args: &[&str]| {
+        Command::new("git")
+            .args(args)
+            .current_dir(crate::root_dir())
+            .output()
+            .ok()
+            .filter(|output| output.status.success())
+            .map(|output| String::from_utf8_lossy(&output.stdout).trim().to_string())
+    };
+    match git(&["rev-parse", "--short", "HEAD"]) {
+        Some(hash) => match git(&["status", "--porcelain", "--untracked-files=no"]) {
+            Some(changes) if !changes.is_empty() => format!("{}-dirty", hash),
+            _ => hash,
+        },
+        None => "unknown".into(),
+    }
+}
+
+/// Loads all entries recorded for `year`. A missing file is treated as an empty history.
+pub fn load(year: Option<i16>) -> io::Result<Vec<HistoryEntry>> {
+    match fs::read_to_string(path(year)) {
+        Ok(contents) => Ok(contents
+            .lines()
+            .filter_map(HistoryEntry::from_json)
+            .collect()),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(vec![]),
+        Err(e) => Err(e),
+    }
+}
+
+pub fn append(year: Option<i16>, entries: &[HistoryEntry]) -> io::Result<()> {
+    let mut lines = String::new();
+    for entry in entries {
+        let _ = writeln!(lines, "{}", entry.to_json());
+    }
+    OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path(year))?
+        .write_all(lines.as_bytes())
+}
+
+/// The entries of the most recent run recorded with `profile`.
+pub fn last_run<'a>(history: &'a [HistoryEntry], profile: &str) -> Vec<&'a HistoryEntry> {
+    let last = history
+        .iter()
+        .filter(|entry| entry.profile == profile)
+        .map(|entry| entry.run)
+        .max();
+    history
+        .iter()
+        .filter(|entry| Some(entry.run) == last && entry.profile == profile)
+        .collect()
+}
+
+/// How the timing of a step changed between a recorded run and the current one.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Change {
+    pub day: u8,
+    pub part: u8,
+    pub baseline: Duration,
+    pub current: Duration,
+}
+
+impl Change {
+    /// Relative change in percent, positive if the step got slower.
+    pub fn percent(&self) -> f64 {
+        let baseline = self.baseline.as_secs_f64();
+        if baseline == 0.0 {
+            return 0.0;
+        }
+        (self.current.as_secs_f64() - baseline) / baseline * 100.0
+    }
+
+    pub fn is_regression(&self, threshold_percent: f64) -> bool {
+        self.percent() > threshold_percent
+    }
+}
+
+/// Pairs every solved step in `records` with its timing in `baseline`.
+/// Steps that are missing from either side are left out.
+pub fn compare(baseline: &[&HistoryEntry], records: &[PartRecord]) -> Vec<Change> {
+    records
+        .iter()
+        .filter(|record| record.status == Status::Solved)
+        .filter_map(|record| {
+            let entry = baseline
+                .iter()
+                .find(|entry| (entry.day, entry.part) == (record.day, record.part))?;
+            Some(Change {
+                day: record.day,
+                part: record.part,
+                baseline: entry.median,
+                current: record.elapsed,
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::report::part_record;
+
+    fn entry(run: u64, profile: &str, day: u8, part: u8, micros: u64) -> HistoryEntry {
+        HistoryEntry {
+            run,
+            commit: "5ab38b7".into(),
+            profile: profile.into(),
+            day,
+            part,
+            median: Duration::from_micros(micros),
+        }
+    }
+
+    #[test]
+    fn test_entry_roundtrip() {
+        let entry = entry(1700000000, "release", 7, 2, 25);
+        let json = entry.to_json();
+        assert_eq!(
+            json,
+            "{\"run\":1700000000,\"commit\":\"5ab38b7\",\"profile\":\"release\",\"day\":7,\"part\":2,\"median_ns\":25000}"
+        );
+        assert_eq!(HistoryEntry::from_json(&json), Some(entry));
+    }
+
+    #[test]
+    fn test_last_run_per_profile() {
+        let history = vec![
+            entry(1, "release", 1, 1, 10),
+            entry(2, "release", 1, 1, 12),
+            entry(3, "dev", 1, 1, 90),
+        ];
+        assert_eq!(last_run(&history, "release"), vec![&history[1]]);
+        assert_eq!(last_run(&history, "dev"), vec![&history[2]]);
+        assert!(last_run(&history, "bench").is_empty());
+
+        // a run recorded in nanoseconds follows one recorded in seconds.
+        let history = vec![
+            entry(1700000000, "release", 1, 1, 10),
+            entry(1700000000123456789, "release", 1, 1, 12),
+        ];
+        assert_eq!(last_run(&history, "release"), vec![&history[1]]);
+    }
+
+    #[test]
+    fn test_compare() {
+        let history = [
+            entry(1, "release", 1, 1, 100),
+            entry(1, "release", 1, 2, 100),
+        ];
+        let baseline: Vec<&HistoryEntry> = history.iter().collect();
+        let solved = |day, part, micros| {
+            part_record(
+                day,
+                part,
+                Some("1"),
+                Duration::from_micros(micros),
+                Status::Solved,
+            )
+        };
+        let changes = compare(
+            &baseline,
+            &[solved(1, 1, 150), solved(1, 2, 95), solved(2, 1, 5)],
+        );
+
+        assert_eq!(changes.len(), 2);
+        assert_eq!(changes[0].percent().round(), 50.0);
+        assert!(changes[0].is_regression(10.0));
+        assert!(!changes[1].is_regression(10.0));
+    }
+}
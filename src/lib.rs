@@ -3,92 +3,144 @@
  * There is no need to edit this file unless you want to change template functionality.
  * Prefer `./helpers.rs` if you want to extract code from your solutions.
  */
-use std::env;
+use std::fmt::{self, Display};
 use std::fs;
-
+use std::path::PathBuf;
+
+mod answer;
+pub mod answers;
+pub mod bench;
+pub mod calendar;
+mod catch;
+pub mod config;
+pub mod days;
+pub mod download;
+mod error;
+pub mod example;
 pub mod helpers;
+pub mod history;
+pub mod log;
+pub mod memory;
+pub mod readme;
+pub mod report;
+pub mod runner;
+mod solution;
+mod watch;
+pub mod years;
+
+pub use answer::{Answer, IntoOutcome, Outcome};
+pub use error::{Error, Result};
+pub use solution::Solution;
+
+/// An ANSI escape code that is only printed if output is styled, see
+/// [`config::color_enabled`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Ansi(&'static str);
+
+impl Display for Ansi {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if config::color_enabled() {
+            f.write_str(self.0)?;
+        }
+        Ok(())
+    }
+}
 
-pub const ANSI_ITALIC: &str = "\x1b[3m";
-pub const ANSI_BOLD: &str = "\x1b[1m";
-pub const ANSI_RESET: &str = "\x1b[0m";
+pub const ANSI_ITALIC: Ansi = Ansi("\x1b[3m");
+pub const ANSI_BOLD: Ansi = Ansi("\x1b[1m");
+pub const ANSI_RESET: Ansi = Ansi("\x1b[0m");
 
+/// Runs one part of a day, prints its result and emits a [`report::PartRecord`] for it.
+/// See [`runner::solve_part`].
 #[macro_export]
 macro_rules! solve {
-    ($part:expr, $solver:ident, $input:expr) => {{
-        use advent_of_code::{ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
-        use std::fmt::Display;
-        use std::time::Instant;
-
-        fn print_result<T: Display>(func: impl FnOnce(&str) -> Option<T>, input: &str) {
-            let timer = Instant::now();
-            let result = func(input);
-            let elapsed = timer.elapsed();
-            match result {
-                Some(result) => {
-                    println!(
-                        "{} {}(elapsed: {:.2?}){}",
-                        result, ANSI_ITALIC, elapsed, ANSI_RESET
-                    );
-                }
-                None => {
-                    println!("not solved.")
-                }
-            }
-        }
+    ($day:expr, $part:expr, $solver:expr, $input:expr) => {
+        $crate::runner::solve_part(
+            $day,
+            $part,
+            $solver,
+            $input,
+            &$crate::runner::RunOptions::default(),
+        )
+    };
+    ($day:expr, $part:expr, $solver:expr, $input:expr, $options:expr) => {
+        $crate::runner::solve_part($day, $part, $solver, $input, $options)
+    };
+}
 
-        println!("🎄 {}Part {}{} 🎄", ANSI_BOLD, $part, ANSI_RESET);
-        print_result($solver, $input);
-    }};
+/// Root of the repository. Paths are resolved against it rather than the current directory,
+/// so the binaries can be run from anywhere.
+pub fn root_dir() -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR"))
 }
 
-pub fn read_file(folder: &str, day: u8) -> String {
-    let cwd = env::current_dir().unwrap();
+/// The year whose files live in folders of their own, or `None` for the main year.
+///
+/// The days of the main year, [`config::Config::year`], use the flat layout: `src/days/dayNN.rs`,
+/// `src/bin/NN.rs` and `NN.txt` in the input and example folders. Any other year is kept in
+/// `src/years/yYYYY/dayNN.rs`, `src/bin/YYYY-NN.rs` and a `YYYY/` folder next to the day files
+/// of the main year. `helpers.rs` is shared by all years.
+pub fn separate_year(year: Option<i16>) -> Option<i16> {
+    year.filter(|year| Some(*year) != config::get().year)
+}
 
-    let filepath = cwd.join("src").join(folder).join(format!("{:02}.txt", day));
+/// Name of the binary of a day, `NN` or `YYYY-NN`.
+pub fn bin_name(year: Option<i16>, day: u8) -> String {
+    match year {
+        None => format!("{:02}", day),
+        Some(year) => format!("{}-{:02}", year, day),
+    }
+}
 
-    let f = fs::read_to_string(filepath);
-    f.expect("could not open input file")
+/// Module path of a day relative to the crate root, e.g. `days::day07` or `years::y2021::day07`.
+pub fn module_path(year: Option<i16>, day: u8) -> String {
+    match year {
+        None => format!("days::day{:02}", day),
+        Some(year) => format!("years::y{}::day{:02}", year, day),
+    }
 }
 
-fn parse_time(val: &str, postfix: &str) -> f64 {
-    val.split(postfix).next().unwrap().parse().unwrap()
+/// Path of a folder of day files. `inputs` and `examples` can be moved in [`config::CONFIG_FILE`],
+/// other folders are looked up in `src/`.
+pub fn folder_path(folder: &str) -> PathBuf {
+    let config = config::get();
+    match folder {
+        "inputs" => root_dir().join(&config.inputs_dir),
+        "examples" => root_dir().join(&config.examples_dir),
+        _ => root_dir().join("src").join(folder),
+    }
 }
 
-pub fn parse_exec_time(output: &str) -> f64 {
-    output.lines().fold(0_f64, |acc, l| {
-        if !l.contains("elapsed:") {
-            acc
-        } else {
-            let timing = l.split("(elapsed: ").last().unwrap();
-            // use `contains` istd. of `ends_with`: string may contain ANSI escape sequences.
-            // for possible time formats, see: https://github.com/rust-lang/rust/blob/1.64.0/library/core/src/time.rs#L1176-L1200
-            if timing.contains("ns)") {
-                acc // range below rounding precision.
-            } else if timing.contains("µs)") {
-                acc + parse_time(timing, "µs") / 1000_f64
-            } else if timing.contains("ms)") {
-                acc + parse_time(timing, "ms")
-            } else if timing.contains("s)") {
-                acc + parse_time(timing, "s") * 1000_f64
-            } else {
-                acc
-            }
-        }
-    })
+/// Path of a folder of day files of `year`, see [`separate_year`].
+pub fn year_folder_path(year: Option<i16>, folder: &str) -> PathBuf {
+    match year {
+        None => folder_path(folder),
+        Some(year) => folder_path(folder).join(year.to_string()),
+    }
 }
 
-/// copied from: https://github.com/rust-lang/rust/blob/1.64.0/library/std/src/macros.rs#L328-L333
-#[cfg(test)]
-macro_rules! assert_approx_eq {
-    ($a:expr, $b:expr) => {{
-        let (a, b) = (&$a, &$b);
-        assert!(
-            (*a - *b).abs() < 1.0e-6,
-            "{} is not approximately equal to {}",
-            *a,
-            *b
-        );
-    }};
+pub fn file_path(folder: &str, day: u8) -> PathBuf {
+    year_file_path(None, folder, day)
+}
+
+pub fn year_file_path(year: Option<i16>, folder: &str, day: u8) -> PathBuf {
+    year_folder_path(year, folder).join(format!("{:02}.txt", day))
+}
+
+/// Reads `NN.txt` from a folder, see [`folder_path`]. The front-matter of example files is
+/// stripped, see [`example::Example`].
+pub fn read_file(folder: &str, day: u8) -> Result<String> {
+    read_year_file(None, folder, day)
+}
+
+/// Reads `NN.txt` from a folder of `year`, see [`read_file`] and [`separate_year`].
+pub fn read_year_file(year: Option<i16>, folder: &str, day: u8) -> Result<String> {
+    let path = year_file_path(year, folder, day);
+    let contents = fs::read_to_string(&path).map_err(|source| Error::Read { path, source })?;
+    match folder {
+        "examples" => Ok(example::strip_front_matter(&contents).to_string()),
+        _ => Ok(contents),
+    }
 }
 
 #[cfg(test)]
@@ -96,30 +148,10 @@ mod tests {
     use super::*;
 
     #[test]
-    fn test_parse_exec_time() {
-        assert_approx_eq!(
-            parse_exec_time(&format!(
-                "🎄 Part 1 🎄\n0 (elapsed: 74.13ns){}\n🎄 Part 2 🎄\n0 (elapsed: 50.00ns){}",
-                ANSI_RESET, ANSI_RESET
-            )),
-            0_f64
-        );
-
-        assert_approx_eq!(
-            parse_exec_time("🎄 Part 1 🎄\n0 (elapsed: 755µs)\n🎄 Part 2 🎄\n0 (elapsed: 700µs)"),
-            1.455_f64
-        );
-
-        assert_approx_eq!(
-            parse_exec_time("🎄 Part 1 🎄\n0 (elapsed: 70µs)\n🎄 Part 2 🎄\n0 (elapsed: 1.45ms)"),
-            1.52_f64
-        );
-
-        assert_approx_eq!(
-            parse_exec_time(
-                "🎄 Part 1 🎄\n0 (elapsed: 10.3s)\n🎄 Part 2 🎄\n0 (elapsed: 100.50ms)"
-            ),
-            10400.50_f64
-        );
+    fn test_day_names() {
+        assert_eq!(bin_name(None, 7), "07");
+        assert_eq!(bin_name(Some(2021), 7), "2021-07");
+        assert_eq!(module_path(None, 7), "days::day07");
+        assert_eq!(module_path(Some(2021), 7), "years::y2021::day07");
     }
 }
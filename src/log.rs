@@ -0,0 +1,126 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::sync::atomic::{AtomicU8, Ordering};
+
+/// How much debug output solutions print, set with `--verbose`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Verbosity {
+    #[default]
+    Quiet,
+    /// `--verbose` or `-v`: enables [`debug!`](crate::debug).
+    Debug,
+    /// `--verbose --verbose` or `-vv`: also enables [`trace!`](crate::trace).
+    Trace,
+}
+
+impl Verbosity {
+    pub fn from_args(args: &mut pico_args::Arguments) -> Verbosity {
+        let mut level = 0;
+        while args.contains(["-v", "--verbose"]) {
+            level += 1;
+        }
+        if args.contains("-vv") {
+            level += 2;
+        }
+        Verbosity::from_level(level)
+    }
+
+    fn from_level(level: u8) -> Verbosity {
+        match level {
+            0 => Verbosity::Quiet,
+            1 => Verbosity::Debug,
+            _ => Verbosity::Trace,
+        }
+    }
+
+    /// Arguments that make a day binary run with this verbosity.
+    pub fn to_args(self) -> Vec<String> {
+        match self {
+            Verbosity::Quiet => vec![],
+            Verbosity::Debug => vec!["--verbose".into()],
+            Verbosity::Trace => vec!["-vv".into()],
+        }
+    }
+}
+
+static VERBOSITY: AtomicU8 = AtomicU8::new(0);
+
+pub fn verbosity() -> Verbosity {
+    Verbosity::from_level(VERBOSITY.load(Ordering::Relaxed))
+}
+
+pub fn set_verbosity(verbosity: Verbosity) {
+    VERBOSITY.store(verbosity as u8, Ordering::Relaxed);
+}
+
+/// Whether output at `level` is printed. Checked by the macros before formatting anything.
+pub fn enabled(level: Verbosity) -> bool {
+    verbosity() >= level
+}
+
+/// Restores the verbosity when dropped, also if `f` of [`silenced`] panics.
+struct Restore(Verbosity);
+
+impl Drop for Restore {
+    fn drop(&mut self) {
+        set_verbosity(self.0);
+    }
+}
+
+/// Runs `f` with debug output turned off, e.g. while benchmarking a step.
+pub fn silenced<T>(f: impl FnOnce() -> T) -> T {
+    let _restore = Restore(verbosity());
+    set_verbosity(Verbosity::Quiet);
+    f()
+}
+
+/// Prints to stderr if the day runs with `--verbose`. Use this instead of `println!` for output
+/// that helps while solving, so it does not get mixed up with the answers.
+#[macro_export]
+macro_rules! debug {
+    ($($arg:tt)*) => {
+        if $crate::log::enabled($crate::log::Verbosity::Debug) {
+            eprintln!($($arg)*);
+        }
+    };
+}
+
+/// Like [`debug!`](crate::debug), for very detailed output only shown with `-vv`.
+#[macro_export]
+macro_rules! trace {
+    ($($arg:tt)*) => {
+        if $crate::log::enabled($crate::log::Verbosity::Trace) {
+            eprintln!($($arg)*);
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(values: &[&str]) -> Verbosity {
+        let args = values.iter().map(|arg| arg.into()).collect();
+        Verbosity::from_args(&mut pico_args::Arguments::from_vec(args))
+    }
+
+    #[test]
+    fn test_verbosity_from_args() {
+        assert_eq!(parse(&[]), Verbosity::Quiet);
+        assert_eq!(parse(&["--verbose"]), Verbosity::Debug);
+        assert_eq!(parse(&["-v"]), Verbosity::Debug);
+        assert_eq!(parse(&["-v", "--verbose"]), Verbosity::Trace);
+        assert_eq!(parse(&["-vv"]), Verbosity::Trace);
+    }
+
+    #[test]
+    fn test_silenced_restores_verbosity_after_panic() {
+        set_verbosity(Verbosity::Debug);
+        let result = std::panic::catch_unwind(|| silenced(|| panic!("sample failed")));
+        assert!(result.is_err());
+        assert_eq!(verbosity(), Verbosity::Debug);
+        set_verbosity(Verbosity::Quiet);
+    }
+}
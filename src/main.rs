@@ -2,45 +2,62 @@
  * This file contains template code.
  * There is no need to edit this file unless you want to change template functionality.
  */
-use advent_of_code::{ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
-use std::process::Command;
+use advent_of_code::config;
+use commands::CommandError;
+use std::process;
 
-fn main() {
-    let total: f64 = (1..=25)
-        .map(|day| {
-            let day = format!("{:02}", day);
+mod commands;
 
-            let cmd = Command::new("cargo")
-                .args(["run", "--release", "--bin", &day])
-                .output()
-                .unwrap();
+const USAGE: &str = "Usage: cargo aoc <command> [<args>]
 
-            println!("----------");
-            println!("{}| Day {} |{}", ANSI_BOLD, day, ANSI_RESET);
-            println!("----------");
+Commands:
+  scaffold <day>         Create the module, binary, input and example files of a day
+  download <days>        Download the puzzle inputs of a day, a range or --all with aoc-cli
+  solve <day>            Run one day, takes the same flags as `cargo solve <day>`
+  all                    Run all days, the default when no command is given
+  bench [<day>]          Benchmark one day, or all days
+  test [<day>]           Run the tests of one day, or all tests
+  submit <day> <part>    Submit the answer of a part with aoc-cli
+  progress               Show which days are scaffolded and solved
+  help                   Show this message
 
-            let output = String::from_utf8(cmd.stdout).unwrap();
-            let is_empty = output.is_empty();
+All commands but help take `--year <year>` to work on the days of another year, and
+`--color <always|auto|never>` to override the `color` setting.";
 
-            println!(
-                "{}",
-                if is_empty {
-                    "Not solved."
-                } else {
-                    output.trim()
-                }
-            );
+fn main() {
+    let mut args = pico_args::Arguments::from_env();
+    let command = match config::color_from_args(&mut args).and_then(|()| args.subcommand()) {
+        Ok(command) => command,
+        Err(e) => {
+            eprintln!("{}", CommandError::Args(e));
+            process::exit(1);
+        }
+    };
 
-            if is_empty {
-                0_f64
-            } else {
-                advent_of_code::parse_exec_time(&output)
-            }
-        })
-        .sum();
+    let result = match command.as_deref() {
+        // `cargo all` runs this binary with flags only.
+        None | Some("all") => commands::all::run(args),
+        Some("scaffold") => commands::scaffold::run(args),
+        Some("download") => commands::download::run(args),
+        Some("solve") => commands::solve::run(args),
+        Some("bench") => commands::bench::run(args),
+        Some("test") => commands::test::run(args),
+        Some("submit") => commands::submit::run(args),
+        Some("progress") => commands::progress::run(args),
+        Some("help") => {
+            println!("{}", USAGE);
+            Ok(())
+        }
+        Some(command) => Err(CommandError::failed(format!(
+            "Unknown command \"{}\".\n\n{}",
+            command, USAGE
+        ))),
+    };
 
-    println!(
-        "{}Total:{} {}{:.2}ms{}",
-        ANSI_BOLD, ANSI_RESET, ANSI_ITALIC, total, ANSI_RESET
-    );
+    if let Err(e) = result {
+        if !matches!(e, CommandError::Reported) {
+            eprintln!("{}", e);
+        }
+        process::exit(1);
+    }
 }
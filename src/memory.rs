@@ -0,0 +1,169 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::fmt::{self, Display};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+static ALLOCATED_BYTES: AtomicUsize = AtomicUsize::new(0);
+static LIVE_BYTES: AtomicUsize = AtomicUsize::new(0);
+static PEAK_BYTES: AtomicUsize = AtomicUsize::new(0);
+
+/// Wraps the system allocator and counts every allocation. Installed as the global allocator
+/// when the `alloc-stats` feature is enabled.
+pub struct CountingAllocator;
+
+impl CountingAllocator {
+    fn add(size: usize) {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        ALLOCATED_BYTES.fetch_add(size, Ordering::Relaxed);
+        let live = LIVE_BYTES.fetch_add(size, Ordering::Relaxed) + size;
+        PEAK_BYTES.fetch_max(live, Ordering::Relaxed);
+    }
+
+    fn remove(size: usize) {
+        LIVE_BYTES.fetch_sub(size, Ordering::Relaxed);
+    }
+}
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc(layout);
+        if !ptr.is_null() {
+            Self::add(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc_zeroed(layout);
+        if !ptr.is_null() {
+            Self::add(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        Self::remove(layout.size());
+    }
+
+    /// A reallocation counts as a new allocation of `new_size` bytes, e.g. a `Vec` growing.
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new_ptr = System.realloc(ptr, layout, new_size);
+        if !new_ptr.is_null() {
+            Self::remove(layout.size());
+            Self::add(new_size);
+        }
+        new_ptr
+    }
+}
+
+#[cfg(feature = "alloc-stats")]
+#[global_allocator]
+static GLOBAL: CountingAllocator = CountingAllocator;
+
+/// Whether the counting allocator is installed, i.e. the `alloc-stats` feature is enabled.
+pub const fn enabled() -> bool {
+    cfg!(feature = "alloc-stats")
+}
+
+/// Heap usage of one step.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct AllocStats {
+    /// Number of allocations, including reallocations.
+    pub allocations: usize,
+    /// Sum of the sizes of all allocations.
+    pub allocated_bytes: usize,
+    /// Largest amount of memory the step held at once, on top of what was allocated before
+    /// it started.
+    pub peak_bytes: usize,
+}
+
+impl Display for AllocStats {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "(allocations: {}, allocated: {}, peak: {})",
+            self.allocations,
+            format_bytes(self.allocated_bytes),
+            format_bytes(self.peak_bytes)
+        )
+    }
+}
+
+pub fn format_bytes(bytes: usize) -> String {
+    const UNITS: [&str; 3] = ["KiB", "MiB", "GiB"];
+    if bytes < 1024 {
+        return format!("{} B", bytes);
+    }
+    let mut value = bytes as f64 / 1024.0;
+    let mut unit = 0;
+    while value >= 1024.0 && unit < UNITS.len() - 1 {
+        value /= 1024.0;
+        unit += 1;
+    }
+    format!("{:.2} {}", value, UNITS[unit])
+}
+
+/// Runs `f` and counts the allocations it makes. Returns `None` for the stats if the counting
+/// allocator is not installed.
+///
+/// The counters are shared by all threads, so allocations of other threads running at the same
+/// time are counted as well.
+pub fn measure<T>(f: impl FnOnce() -> T) -> (T, Option<AllocStats>) {
+    if !enabled() {
+        return (f(), None);
+    }
+
+    let allocations = ALLOCATIONS.load(Ordering::Relaxed);
+    let allocated_bytes = ALLOCATED_BYTES.load(Ordering::Relaxed);
+    let live_bytes = LIVE_BYTES.load(Ordering::Relaxed);
+    PEAK_BYTES.store(live_bytes, Ordering::Relaxed);
+
+    let result = f();
+
+    let stats = AllocStats {
+        allocations: ALLOCATIONS.load(Ordering::Relaxed) - allocations,
+        allocated_bytes: ALLOCATED_BYTES.load(Ordering::Relaxed) - allocated_bytes,
+        peak_bytes: PEAK_BYTES
+            .load(Ordering::Relaxed)
+            .saturating_sub(live_bytes),
+    };
+    (result, Some(stats))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_bytes() {
+        assert_eq!(format_bytes(0), "0 B");
+        assert_eq!(format_bytes(1023), "1023 B");
+        assert_eq!(format_bytes(1536), "1.50 KiB");
+        assert_eq!(format_bytes(3 * 1024 * 1024), "3.00 MiB");
+    }
+
+    #[test]
+    #[cfg(feature = "alloc-stats")]
+    fn test_measure() {
+        let (values, stats) = measure(|| {
+            let values: Vec<u64> = Vec::with_capacity(1000);
+            drop(Vec::<u64>::with_capacity(2000));
+            values
+        });
+        let stats = stats.unwrap();
+        assert_eq!(values.capacity(), 1000);
+        assert!(stats.allocations >= 2);
+        assert!(stats.allocated_bytes >= 24000);
+    }
+
+    #[test]
+    #[cfg(not(feature = "alloc-stats"))]
+    fn test_measure_without_feature() {
+        assert_eq!(measure(|| 42), (42, None));
+    }
+}
@@ -0,0 +1,125 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use crate::report::{PartRecord, Status, PARSE_STEP};
+use crate::runner;
+use std::fmt::Write as _;
+use std::path::PathBuf;
+use std::{fs, io};
+
+/// Marks the start and the end of the section `cargo all -- --readme` rewrites.
+pub const MARKER: &str = "<!--- benchmarking table --->";
+
+pub fn path() -> PathBuf {
+    crate::root_dir().join("README.md")
+}
+
+fn format_timing(records: &[PartRecord], day: u8, part: u8) -> String {
+    records
+        .iter()
+        .find(|record| record.day == day && record.part == part)
+        .filter(|record| record.status == Status::Solved)
+        .map(|record| format!("`{:.2?}`", record.elapsed))
+        .unwrap_or_else(|| "-".into())
+}
+
+/// The benchmark section for `records`, including the markers.
+pub fn benchmark_table(records: &[PartRecord]) -> String {
+    let mut days: Vec<u8> = records.iter().map(|record| record.day).collect();
+    days.dedup();
+
+    let mut table = format!(
+        "{}\n## Benchmarks\n\n| Day | Parse | Part 1 | Part 2 |\n| :---: | :---: | :---: | :---: |\n",
+        MARKER
+    );
+    for day in days {
+        let _ = writeln!(
+            table,
+            "| [Day {}](./src/days/day{:02}.rs) | {} | {} | {} |",
+            day,
+            day,
+            format_timing(records, day, PARSE_STEP),
+            format_timing(records, day, 1),
+            format_timing(records, day, 2)
+        );
+    }
+    let total = runner::total_elapsed(records);
+    let _ = write!(
+        table,
+        "\n**Total: {:.2}ms**\n{}",
+        total.as_secs_f64() * 1000_f64,
+        MARKER
+    );
+    table
+}
+
+/// Replaces the section between the two markers in `readme` with `table`.
+/// Returns `None` if the markers are missing.
+pub fn replace_table(readme: &str, table: &str) -> Option<String> {
+    let start = readme.find(MARKER)?;
+    let end = start + MARKER.len() + readme[start + MARKER.len()..].find(MARKER)? + MARKER.len();
+    Some(format!("{}{}{}", &readme[..start], table, &readme[end..]))
+}
+
+/// Rewrites the benchmark section of the README with the timings in `records`.
+pub fn update(records: &[PartRecord]) -> io::Result<()> {
+    let readme = fs::read_to_string(path())?;
+    let updated = replace_table(&readme, &benchmark_table(records)).ok_or_else(|| {
+        io::Error::other(format!(
+            "could not find two \"{}\" markers to put the table between",
+            MARKER
+        ))
+    })?;
+    fs::write(path(), updated)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::report::part_record;
+    use std::time::Duration;
+
+    #[test]
+    fn test_benchmark_table() {
+        let records = [
+            part_record(
+                1,
+                PARSE_STEP,
+                Some("1"),
+                Duration::from_micros(20),
+                Status::Solved,
+            ),
+            part_record(1, 1, Some("1"), Duration::from_micros(30), Status::Solved),
+            part_record(1, 2, None, Duration::from_micros(50), Status::Unsolved),
+        ];
+        assert_eq!(
+            benchmark_table(&records),
+            "<!--- benchmarking table --->
+## Benchmarks
+
+| Day | Parse | Part 1 | Part 2 |
+| :---: | :---: | :---: | :---: |
+| [Day 1](./src/days/day01.rs) | `20.00µs` | `30.00µs` | - |
+
+**Total: 0.05ms**
+<!--- benchmarking table --->"
+        );
+    }
+
+    #[test]
+    fn test_replace_table() {
+        let readme = "# AoC\n\n<!--- benchmarking table --->\nold\n<!--- benchmarking table --->\n\n## Usage\n";
+        assert_eq!(
+            replace_table(readme, "<!--- benchmarking table --->\nnew\n<!--- benchmarking table --->"),
+            Some(
+                "# AoC\n\n<!--- benchmarking table --->\nnew\n<!--- benchmarking table --->\n\n## Usage\n"
+                    .into()
+            )
+        );
+        assert_eq!(
+            replace_table("# AoC\n<!--- benchmarking table --->\n", "new"),
+            None
+        );
+    }
+}
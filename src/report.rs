@@ -0,0 +1,427 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use crate::answers::Verdict;
+use crate::bench::BenchStats;
+use std::env;
+use std::fmt::Write as _;
+use std::fs::OpenOptions;
+use std::io::Write;
+use std::time::Duration;
+
+/// When set, `solve!` appends one JSON record per part to the file at this path.
+pub const REPORT_FILE_ENV: &str = "AOC_REPORT_FILE";
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Status {
+    Solved,
+    Unsolved,
+    /// The step returned an error, see [`PartRecord::error`].
+    Failed,
+}
+
+impl Status {
+    fn as_str(&self) -> &'static str {
+        match self {
+            Status::Solved => "solved",
+            Status::Unsolved => "unsolved",
+            Status::Failed => "failed",
+        }
+    }
+
+    fn from_str(s: &str) -> Option<Status> {
+        match s {
+            "solved" => Some(Status::Solved),
+            "unsolved" => Some(Status::Unsolved),
+            "failed" => Some(Status::Failed),
+            _ => None,
+        }
+    }
+}
+
+/// Value of [`PartRecord::part`] for the record describing a day's parse step.
+pub const PARSE_STEP: u8 = 0;
+
+/// Machine-readable result of running one part of a day.
+/// Days that declare a parse step emit an additional record with `part` set to [`PARSE_STEP`].
+/// In `--bench` mode, `elapsed` is the median of all samples and `stats` holds the details.
+/// `verdict` is set for parts that were checked against the locked answers.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PartRecord {
+    pub day: u8,
+    pub part: u8,
+    pub answer: Option<String>,
+    pub elapsed: Duration,
+    pub status: Status,
+    pub stats: Option<BenchStats>,
+    pub verdict: Option<Verdict>,
+    pub error: Option<String>,
+}
+
+impl PartRecord {
+    /// Serializes the record as a single line of JSON, without trailing newline.
+    pub fn to_json(&self) -> String {
+        let answer = match &self.answer {
+            Some(answer) => json_string(answer),
+            None => "null".into(),
+        };
+        let stats = match &self.stats {
+            Some(stats) => format!(
+                ",\"samples\":{},\"min_ns\":{},\"median_ns\":{},\"mean_ns\":{},\"stddev_ns\":{}",
+                stats.samples,
+                stats.min.as_nanos(),
+                stats.median.as_nanos(),
+                stats.mean.as_nanos(),
+                stats.stddev.as_nanos()
+            ),
+            None => String::new(),
+        };
+        let verdict = match &self.verdict {
+            Some(Verdict::Wrong { expected }) => {
+                format!(
+                    ",\"verdict\":\"wrong\",\"expected\":{}",
+                    json_string(expected)
+                )
+            }
+            Some(verdict) => format!(",\"verdict\":\"{}\"", verdict.as_str()),
+            None => String::new(),
+        };
+        let error = match &self.error {
+            Some(error) => format!(",\"error\":{}", json_string(error)),
+            None => String::new(),
+        };
+        format!(
+            "{{\"day\":{},\"part\":{},\"answer\":{},\"elapsed_ns\":{},\"status\":\"{}\"{}{}{}}}",
+            self.day,
+            self.part,
+            answer,
+            self.elapsed.as_nanos(),
+            self.status.as_str(),
+            stats,
+            verdict,
+            error
+        )
+    }
+
+    /// Parses a line previously written by [`PartRecord::to_json`].
+    pub fn from_json(line: &str) -> Option<PartRecord> {
+        let mut day = None;
+        let mut part = None;
+        let mut answer = None;
+        let mut elapsed = None;
+        let mut status = None;
+        let mut samples = None;
+        let mut stat_nanos = [None; 4];
+        let mut verdict = None;
+        let mut expected = None;
+        let mut error = None;
+
+        for (key, value) in parse_object(line)? {
+            match (key.as_str(), value) {
+                ("samples", Value::Number(n)) => samples = u32::try_from(n).ok(),
+                ("verdict", Value::String(s)) => verdict = Some(s),
+                ("expected", Value::String(s)) => expected = Some(s),
+                ("error", Value::String(s)) => error = Some(s),
+                ("min_ns", Value::Number(n)) => stat_nanos[0] = duration_from_nanos(n),
+                ("median_ns", Value::Number(n)) => stat_nanos[1] = duration_from_nanos(n),
+                ("mean_ns", Value::Number(n)) => stat_nanos[2] = duration_from_nanos(n),
+                ("stddev_ns", Value::Number(n)) => stat_nanos[3] = duration_from_nanos(n),
+                ("day", Value::Number(n)) => day = u8::try_from(n).ok(),
+                ("part", Value::Number(n)) => part = u8::try_from(n).ok(),
+                ("answer", Value::String(s)) => answer = Some(s),
+                ("answer", Value::Null) => {}
+                ("elapsed_ns", Value::Number(n)) => elapsed = duration_from_nanos(n),
+                ("status", Value::String(s)) => status = Status::from_str(&s),
+                _ => return None,
+            }
+        }
+
+        let stats = match (samples, stat_nanos) {
+            (Some(samples), [Some(min), Some(median), Some(mean), Some(stddev)]) => {
+                Some(BenchStats {
+                    samples,
+                    min,
+                    median,
+                    mean,
+                    stddev,
+                })
+            }
+            _ => None,
+        };
+
+        let verdict = match (verdict.as_deref(), expected) {
+            (None, _) => None,
+            (Some("correct"), _) => Some(Verdict::Correct),
+            (Some("wrong"), Some(expected)) => Some(Verdict::Wrong { expected }),
+            (Some("unverified"), _) => Some(Verdict::Unverified),
+            (Some("input_changed"), _) => Some(Verdict::InputChanged),
+            _ => return None,
+        };
+
+        Some(PartRecord {
+            day: day?,
+            part: part?,
+            answer,
+            elapsed: elapsed?,
+            status: status?,
+            stats,
+            verdict,
+            error,
+        })
+    }
+}
+
+/// A record without bench stats, verdict or error, for tests.
+#[cfg(test)]
+pub(crate) fn part_record(
+    day: u8,
+    part: u8,
+    answer: Option<&str>,
+    elapsed: Duration,
+    status: Status,
+) -> PartRecord {
+    PartRecord {
+        day,
+        part,
+        answer: answer.map(String::from),
+        elapsed,
+        status,
+        stats: None,
+        verdict: None,
+        error: None,
+    }
+}
+
+pub(crate) fn duration_from_nanos(n: u128) -> Option<Duration> {
+    u64::try_from(n).ok().map(Duration::from_nanos)
+}
+
+/// Appends `record` to the report file, if the current process was asked for one.
+pub fn emit(record: &PartRecord) {
+    let path = match env::var_os(REPORT_FILE_ENV) {
+        Some(path) => path,
+        None => return,
+    };
+
+    let file = OpenOptions::new().create(true).append(true).open(&path);
+    if let Err(e) = file.and_then(|mut f| writeln!(f, "{}", record.to_json())) {
+        eprintln!("could not write result record: {}", e);
+    }
+}
+
+/// Reads all records from a report file, skipping lines that are not valid records.
+pub fn read_records(contents: &str) -> Vec<PartRecord> {
+    contents.lines().filter_map(PartRecord::from_json).collect()
+}
+
+pub(crate) fn json_string(s: &str) -> String {
+    let mut out = String::with_capacity(s.len() + 2);
+    out.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if (c as u32) < 0x20 => {
+                let _ = write!(out, "\\u{:04x}", c as u32);
+            }
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+    out
+}
+
+pub(crate) enum Value {
+    Null,
+    Number(u128),
+    String(String),
+}
+
+/// Minimal parser for the flat objects written by `PartRecord::to_json`.
+pub(crate) fn parse_object(line: &str) -> Option<Vec<(String, Value)>> {
+    let mut chars = line.trim().chars().peekable();
+    let mut fields = Vec::new();
+
+    if chars.next()? != '{' {
+        return None;
+    }
+
+    loop {
+        match chars.next()? {
+            '}' => break,
+            ',' => continue,
+            '"' => {
+                let key = parse_string(&mut chars)?;
+                if chars.next()? != ':' {
+                    return None;
+                }
+                let value = match chars.peek()? {
+                    '"' => {
+                        chars.next();
+                        Value::String(parse_string(&mut chars)?)
+                    }
+                    'n' => {
+                        let null: String = chars.by_ref().take(4).collect();
+                        if null != "null" {
+                            return None;
+                        }
+                        Value::Null
+                    }
+                    _ => {
+                        let mut digits = String::new();
+                        while let Some(c) = chars.peek().filter(|c| c.is_ascii_digit()) {
+                            digits.push(*c);
+                            chars.next();
+                        }
+                        Value::Number(digits.parse().ok()?)
+                    }
+                };
+                fields.push((key, value));
+            }
+            _ => return None,
+        }
+    }
+
+    Some(fields)
+}
+
+fn parse_string(chars: &mut impl Iterator<Item = char>) -> Option<String> {
+    let mut out = String::new();
+    loop {
+        match chars.next()? {
+            '"' => return Some(out),
+            '\\' => match chars.next()? {
+                'n' => out.push('\n'),
+                'r' => out.push('\r'),
+                't' => out.push('\t'),
+                'u' => {
+                    let code: String = chars.take(4).collect();
+                    out.push(char::from_u32(u32::from_str_radix(&code, 16).ok()?)?);
+                }
+                c => out.push(c),
+            },
+            c => out.push(c),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_record_roundtrip() {
+        let record = part_record(
+            7,
+            1,
+            Some("95437"),
+            Duration::from_nanos(1_450_000),
+            Status::Solved,
+        );
+        assert_eq!(
+            record.to_json(),
+            r#"{"day":7,"part":1,"answer":"95437","elapsed_ns":1450000,"status":"solved"}"#
+        );
+        assert_eq!(PartRecord::from_json(&record.to_json()), Some(record));
+    }
+
+    #[test]
+    fn test_record_escapes_answer() {
+        let record = part_record(
+            10,
+            2,
+            Some("#..\"#\\\n.#\t#"),
+            Duration::from_secs(10),
+            Status::Solved,
+        );
+        assert_eq!(PartRecord::from_json(&record.to_json()), Some(record));
+    }
+
+    #[test]
+    fn test_record_with_bench_stats() {
+        let record = PartRecord {
+            stats: Some(BenchStats {
+                samples: 1000,
+                min: Duration::from_nanos(2_100),
+                median: Duration::from_nanos(2_500),
+                mean: Duration::from_nanos(2_650),
+                stddev: Duration::from_nanos(310),
+            }),
+            ..part_record(
+                1,
+                2,
+                Some("45000"),
+                Duration::from_nanos(2_500),
+                Status::Solved,
+            )
+        };
+        assert!(record.to_json().ends_with(
+            r#""samples":1000,"min_ns":2100,"median_ns":2500,"mean_ns":2650,"stddev_ns":310}"#
+        ));
+        assert_eq!(PartRecord::from_json(&record.to_json()), Some(record));
+    }
+
+    #[test]
+    fn test_record_unsolved() {
+        let record = PartRecord::from_json(
+            r#"{"day":5,"part":2,"answer":null,"elapsed_ns":74,"status":"unsolved"}"#,
+        )
+        .unwrap();
+        assert_eq!(record.answer, None);
+        assert_eq!(record.status, Status::Unsolved);
+        assert_eq!(record.elapsed, Duration::from_nanos(74));
+    }
+
+    #[test]
+    fn test_record_with_verdict() {
+        let record = PartRecord {
+            verdict: Some(Verdict::Wrong {
+                expected: "24933642".into(),
+            }),
+            ..part_record(
+                7,
+                2,
+                Some("24933641"),
+                Duration::from_nanos(5_580),
+                Status::Solved,
+            )
+        };
+        assert!(record
+            .to_json()
+            .ends_with(r#""verdict":"wrong","expected":"24933642"}"#));
+        assert_eq!(PartRecord::from_json(&record.to_json()), Some(record));
+
+        let record = PartRecord {
+            verdict: Some(Verdict::InputChanged),
+            ..PartRecord::from_json(
+                r#"{"day":1,"part":1,"answer":"6","elapsed_ns":1,"status":"solved"}"#,
+            )
+            .unwrap()
+        };
+        assert_eq!(PartRecord::from_json(&record.to_json()), Some(record));
+    }
+
+    #[test]
+    fn test_record_failed() {
+        let record = PartRecord {
+            error: Some("invalid input: unknown direction \"X\"".into()),
+            ..part_record(9, 1, None, Duration::from_nanos(1_200), Status::Failed)
+        };
+        assert!(record
+            .to_json()
+            .ends_with(r#""status":"failed","error":"invalid input: unknown direction \"X\""}"#));
+        assert_eq!(PartRecord::from_json(&record.to_json()), Some(record));
+    }
+
+    #[test]
+    fn test_read_records_skips_noise() {
+        let contents = "not json\n{\"day\":1,\"part\":1,\"answer\":\"6\",\"elapsed_ns\":37030,\"status\":\"solved\"}\n{\"day\":1}\n";
+        let records = read_records(contents);
+        assert_eq!(records.len(), 1);
+        assert_eq!(records[0].answer.as_deref(), Some("6"));
+    }
+}
@@ -0,0 +1,805 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use crate::answers::{self, AnswerBook, Verdict};
+use crate::bench::{self, BenchOptions, BenchStats};
+use crate::catch::catch_panic;
+use crate::config;
+use crate::log::{self, Verbosity};
+use crate::memory::{self, AllocStats};
+use crate::report::{self, PartRecord, Status, PARSE_STEP};
+use crate::watch;
+use crate::{
+    read_year_file, Error, IntoOutcome, Outcome, Result, Solution, ANSI_BOLD, ANSI_ITALIC,
+    ANSI_RESET,
+};
+use std::fmt::Display;
+use std::io::{self, Read};
+use std::path::PathBuf;
+use std::process::Command;
+use std::sync::mpsc::{self, RecvTimeoutError};
+use std::time::{Duration, Instant};
+use std::{env, fs, process, thread};
+
+/// Where a day reads its input from.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub enum InputSource {
+    /// `src/inputs/NN.txt`, the default. See [`separate_year`](crate::separate_year) for the
+    /// files of other years.
+    #[default]
+    Puzzle,
+    /// `src/examples/NN.txt`, selected with `--example`.
+    Example,
+    /// Any file, selected with `--input <path>`.
+    File(PathBuf),
+    /// Standard input, selected with `--input -`.
+    Stdin,
+}
+
+impl InputSource {
+    pub fn from_args(args: &mut pico_args::Arguments) -> Result<InputSource, pico_args::Error> {
+        let example = args.contains("--example");
+        let input: Option<String> = args.opt_value_from_str("--input")?;
+
+        match (example, input) {
+            (true, Some(_)) => Err(pico_args::Error::ArgumentParsingFailed {
+                cause: "--example and --input cannot be combined".into(),
+            }),
+            (true, None) => Ok(InputSource::Example),
+            (false, Some(path)) if path == "-" => Ok(InputSource::Stdin),
+            (false, Some(path)) => Ok(InputSource::File(path.into())),
+            (false, None) => Ok(InputSource::Puzzle),
+        }
+    }
+
+    pub fn read(&self, year: Option<i16>, day: u8) -> Result<String> {
+        match self {
+            InputSource::Puzzle => read_year_file(year, "inputs", day),
+            InputSource::Example => read_year_file(year, "examples", day),
+            InputSource::File(path) => fs::read_to_string(path).map_err(|source| Error::Read {
+                path: path.clone(),
+                source,
+            }),
+            InputSource::Stdin => {
+                let mut input = String::new();
+                io::stdin()
+                    .read_to_string(&mut input)
+                    .map_err(|source| Error::Read {
+                        path: "<stdin>".into(),
+                        source,
+                    })?;
+                Ok(input)
+            }
+        }
+    }
+
+    /// Arguments that make a day binary read from this source.
+    pub fn to_args(&self) -> Vec<String> {
+        match self {
+            InputSource::Puzzle => vec![],
+            InputSource::Example => vec!["--example".into()],
+            InputSource::File(path) => vec!["--input".into(), path.display().to_string()],
+            InputSource::Stdin => vec!["--input".into(), "-".into()],
+        }
+    }
+}
+
+/// Flags shared by `cargo solve` and `cargo all`.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct RunOptions {
+    /// Set by `--bench`: repeat every step and report statistics instead of a single timing.
+    pub bench: Option<BenchOptions>,
+    /// Set by `--lock`: record the answers of this run as the correct ones.
+    pub lock: bool,
+    pub input: InputSource,
+    /// Set by `--part <1|2>`: run only this part. The parse step always runs.
+    pub part: Option<u8>,
+    pub verbosity: Verbosity,
+    /// Set by `--part-timeout <secs>`: stop the day if a step runs for longer than this.
+    pub part_timeout: Option<Duration>,
+    /// Locked answers to check results against. Parts are not verified when unset.
+    pub answers: Option<AnswerBook>,
+    /// Hash of the input the current day runs against, set by the runner.
+    input_hash: Option<String>,
+}
+
+impl RunOptions {
+    pub fn from_args(args: &mut pico_args::Arguments) -> Result<RunOptions, pico_args::Error> {
+        let bench = args.contains("--bench");
+        let config = config::get();
+        let defaults = &config.bench;
+        let warmup = args.opt_value_from_str("--warmup")?;
+        let iterations = args.opt_value_from_str("--iterations")?;
+        let time_budget: Option<u64> = args.opt_value_from_str("--time-budget")?;
+        let lock = args.contains("--lock");
+        let input = InputSource::from_args(args)?;
+        let part = args.opt_value_from_str("--part")?;
+
+        if part.is_some_and(|part| part != 1 && part != 2) {
+            return Err(pico_args::Error::ArgumentParsingFailed {
+                cause: "--part must be 1 or 2".into(),
+            });
+        }
+
+        // answers are locked against the puzzle input only, examples have answers of their own.
+        if lock && input != InputSource::Puzzle {
+            return Err(pico_args::Error::ArgumentParsingFailed {
+                cause: "--lock can only be used with the puzzle input".into(),
+            });
+        }
+
+        Ok(RunOptions {
+            bench: bench.then(|| BenchOptions {
+                warmup: warmup.unwrap_or(defaults.warmup),
+                iterations: iterations.or(defaults.iterations),
+                time_budget: time_budget
+                    .map(Duration::from_millis)
+                    .unwrap_or(defaults.time_budget),
+            }),
+            lock,
+            input,
+            part,
+            verbosity: Verbosity::from_args(args),
+            part_timeout: args
+                .opt_value_from_fn("--part-timeout", parse_seconds)?
+                .or(config.part_timeout),
+            answers: None,
+            input_hash: None,
+        })
+    }
+
+    /// Copy of these options for running a day against `input`.
+    fn for_input(&self, input: &str) -> RunOptions {
+        RunOptions {
+            input_hash: self.answers.as_ref().map(|_| answers::input_hash(input)),
+            ..self.clone()
+        }
+    }
+
+    pub fn runs_part(&self, part: u8) -> bool {
+        self.part.is_none_or(|selected| selected == part)
+    }
+
+    /// Arguments that make a day binary run with these options.
+    pub fn to_args(&self) -> Vec<String> {
+        let mut args = vec![];
+        if let Some(bench) = &self.bench {
+            args.push("--bench".into());
+            args.push("--warmup".into());
+            args.push(bench.warmup.to_string());
+            if let Some(iterations) = bench.iterations {
+                args.push("--iterations".into());
+                args.push(iterations.to_string());
+            }
+            args.push("--time-budget".into());
+            args.push(bench.time_budget.as_millis().to_string());
+        }
+        args.extend(self.input.to_args());
+        if let Some(part) = self.part {
+            args.push("--part".into());
+            args.push(part.to_string());
+        }
+        args.extend(self.verbosity.to_args());
+        if let Some(timeout) = self.part_timeout {
+            args.push("--part-timeout".into());
+            args.push(timeout.as_secs_f64().to_string());
+        }
+        args
+    }
+}
+
+/// Parses a timeout given in seconds, e.g. `10` or `0.5`.
+pub fn parse_seconds(value: &str) -> Result<Duration, String> {
+    let seconds = value.parse::<f64>().map_err(|e| e.to_string())?;
+    Duration::try_from_secs_f64(seconds).map_err(|e| e.to_string())
+}
+
+/// Parses a single day, e.g. `7` or `07`.
+pub fn parse_day(value: &str) -> Result<u8, String> {
+    match value.trim().parse::<u8>() {
+        Ok(day) if (1..=25).contains(&day) => Ok(day),
+        _ => Err(format!(
+            "invalid day \"{}\", expected 1 to 25",
+            value.trim()
+        )),
+    }
+}
+
+/// Parses a selection of days such as `1-7,9`.
+pub fn parse_days(value: &str) -> Result<Vec<u8>, String> {
+    let mut days = vec![];
+    for range in value.split(',') {
+        match range.split_once('-') {
+            Some((first, last)) => {
+                let (first, last) = (parse_day(first)?, parse_day(last)?);
+                if first > last {
+                    return Err(format!("invalid range \"{}\"", range.trim()));
+                }
+                days.extend(first..=last);
+            }
+            None => days.push(parse_day(range)?),
+        }
+    }
+    days.sort();
+    days.dedup();
+    Ok(days)
+}
+
+/// Exit status of a day binary that was stopped by `--part-timeout`.
+pub const TIMEOUT_EXIT_CODE: i32 = 124;
+
+/// Ends the process if a step runs for longer than `--part-timeout`. A running step cannot be
+/// interrupted otherwise. Dropping the watchdog disarms it.
+struct Watchdog {
+    reset: Option<mpsc::Sender<()>>,
+}
+
+impl Watchdog {
+    fn arm(day: u8, part: u8, timeout: Option<Duration>) -> Watchdog {
+        let timeout = match timeout {
+            Some(timeout) => timeout,
+            None => return Watchdog { reset: None },
+        };
+
+        let (reset, resets) = mpsc::channel::<()>();
+        thread::spawn(move || loop {
+            match resets.recv_timeout(timeout) {
+                // a reset starts the timeout over.
+                Ok(()) => continue,
+                Err(RecvTimeoutError::Disconnected) => return,
+                Err(RecvTimeoutError::Timeout) => {
+                    let error = format!("timed out after {:.2?}", timeout);
+                    println!("failed: {}", error);
+                    report::emit(&PartRecord {
+                        day,
+                        part,
+                        answer: None,
+                        elapsed: timeout,
+                        status: Status::Failed,
+                        stats: None,
+                        verdict: None,
+                        error: Some(error),
+                    });
+                    process::exit(TIMEOUT_EXIT_CODE);
+                }
+            }
+        });
+
+        Watchdog { reset: Some(reset) }
+    }
+
+    /// Starts the timeout over, e.g. before every run of a step in `--bench` mode.
+    fn reset(&self) {
+        if let Some(reset) = &self.reset {
+            #[allow(unused_must_use)]
+            {
+                reset.send(());
+            }
+        }
+    }
+}
+
+/// Benchmarks a step that already ran once, under the same guards: a panic while sampling fails
+/// the step, and `--part-timeout` applies to every single run.
+fn bench_step<T>(
+    bench: &BenchOptions,
+    watchdog: &Watchdog,
+    step: impl FnMut() -> T,
+) -> std::result::Result<BenchStats, String> {
+    catch_panic(|| log::silenced(|| bench::measure_with(bench, || watchdog.reset(), step)))
+        .map_err(|panic| format!("while benchmarking: {}", panic))
+}
+
+fn format_timing(
+    elapsed: Duration,
+    stats: Option<&BenchStats>,
+    memory: Option<&AllocStats>,
+) -> String {
+    let timing = match stats {
+        Some(stats) => format!(
+            "{}(median: {:.2?}, min: {:.2?}, mean: {:.2?}, stddev: {:.2?}, {} runs){}",
+            ANSI_ITALIC,
+            stats.median,
+            stats.min,
+            stats.mean,
+            stats.stddev,
+            stats.samples,
+            ANSI_RESET
+        ),
+        None => format!("{}(elapsed: {:.2?}){}", ANSI_ITALIC, elapsed, ANSI_RESET),
+    };
+    match memory {
+        Some(memory) => format!("{} {}{}{}", timing, ANSI_ITALIC, memory, ANSI_RESET),
+        None => timing,
+    }
+}
+
+/// Runs one part of a day, prints its result and emits a [`PartRecord`] for it.
+/// This is what the `solve!` macro expands to.
+pub fn solve_part<P: ?Sized, R: IntoOutcome>(
+    day: u8,
+    part: u8,
+    solver: impl Fn(&P) -> R,
+    input: &P,
+    options: &RunOptions,
+) -> PartRecord {
+    println!("🎄 {}Part {}{} 🎄", ANSI_BOLD, part, ANSI_RESET);
+
+    // a panicking part fails on its own, the other part still runs.
+    let watchdog = Watchdog::arm(day, part, options.part_timeout);
+    let timer = Instant::now();
+    let (outcome, memory) = memory::measure(|| catch_panic(|| solver(input).into_outcome()));
+    let mut outcome = outcome.unwrap_or_else(Outcome::Failed);
+    let mut elapsed = timer.elapsed();
+
+    let mut stats = None;
+    if let (Outcome::Solved(_), Some(bench)) = (&outcome, &options.bench) {
+        match bench_step(bench, &watchdog, || solver(input)) {
+            Ok(bench_stats) => {
+                elapsed = bench_stats.median;
+                stats = Some(bench_stats);
+            }
+            Err(error) => outcome = Outcome::Failed(error),
+        }
+    }
+    drop(watchdog);
+
+    let answer = match &outcome {
+        Outcome::Solved(answer) => Some(answer.to_string()),
+        _ => None,
+    };
+    // a part without an answer is only compared if an answer was locked for it.
+    let verdict = match (&options.answers, &options.input_hash) {
+        (Some(answers), Some(input_hash)) => {
+            Some(answers.verify(day, part, input_hash, answer.as_deref()))
+                .filter(|verdict| answer.is_some() || *verdict != Verdict::Unverified)
+        }
+        _ => None,
+    };
+    let verdict_text = match &verdict {
+        Some(verdict) => format!(" {}", verdict),
+        None => String::new(),
+    };
+
+    let (status, error) = match outcome {
+        Outcome::Solved(result) => {
+            // multi-line answers (e.g. CRT pictures) keep the timing on a line of its own.
+            let separator = if result.is_multiline() { "\n" } else { " " };
+            println!(
+                "{}{}{}{}",
+                result,
+                separator,
+                format_timing(elapsed, stats.as_ref(), memory.as_ref()),
+                verdict_text
+            );
+            (Status::Solved, None)
+        }
+        Outcome::Unsolved => {
+            println!("not solved.{}", verdict_text);
+            (Status::Unsolved, None)
+        }
+        Outcome::Failed(error) => {
+            println!("failed: {}{}", error, verdict_text);
+            (Status::Failed, Some(error))
+        }
+    };
+
+    let record = PartRecord {
+        day,
+        part,
+        answer,
+        elapsed,
+        status,
+        stats,
+        verdict,
+        error,
+    };
+    report::emit(&record);
+    record
+}
+
+/// Runs the selected parts of a day against `input`.
+fn run_parts<P: ?Sized, A: IntoOutcome, B: IntoOutcome>(
+    day: u8,
+    input: &P,
+    part_one: impl Fn(&P) -> A,
+    part_two: impl Fn(&P) -> B,
+    options: &RunOptions,
+) -> Vec<PartRecord> {
+    let mut records = vec![];
+    if options.runs_part(1) {
+        records.push(crate::solve!(day, 1, part_one, input, options));
+    }
+    if options.runs_part(2) {
+        records.push(crate::solve!(day, 2, part_two, input, options));
+    }
+    records
+}
+
+/// Runs both parts of a day against the raw input.
+pub fn run_day<A: IntoOutcome, B: IntoOutcome>(
+    day: u8,
+    input: &str,
+    part_one: impl Fn(&&str) -> A,
+    part_two: impl Fn(&&str) -> B,
+    options: &RunOptions,
+) -> Vec<PartRecord> {
+    let options = &options.for_input(input);
+    run_parts(day, &input, part_one, part_two, options)
+}
+
+/// Parses the input once, then runs both parts against the parsed value.
+/// If parsing fails, the parts are skipped.
+pub fn run_parsed_day<'a, P, E, A, B>(
+    day: u8,
+    input: &'a str,
+    parse: impl Fn(&'a str) -> Result<P, E>,
+    part_one: impl Fn(&P) -> A,
+    part_two: impl Fn(&P) -> B,
+    options: &RunOptions,
+) -> Vec<PartRecord>
+where
+    E: Display,
+    A: IntoOutcome,
+    B: IntoOutcome,
+{
+    let options = &options.for_input(input);
+
+    println!("🎄 {}Parse{} 🎄", ANSI_BOLD, ANSI_RESET);
+    let watchdog = Watchdog::arm(day, PARSE_STEP, options.part_timeout);
+    let timer = Instant::now();
+    let (parsed, memory) = memory::measure(|| catch_panic(|| parse(input)));
+    let mut elapsed = timer.elapsed();
+
+    let (mut parsed, mut error) = match parsed {
+        Ok(Ok(parsed)) => (Some(parsed), None),
+        Ok(Err(e)) => (None, Some(e.to_string())),
+        Err(panic) => (None, Some(panic)),
+    };
+
+    let mut stats = None;
+    if let (Some(_), Some(bench)) = (&parsed, &options.bench) {
+        match bench_step(bench, &watchdog, || parse(input)) {
+            Ok(bench_stats) => {
+                elapsed = bench_stats.median;
+                stats = Some(bench_stats);
+            }
+            Err(bench_error) => {
+                parsed = None;
+                error = Some(bench_error);
+            }
+        }
+    }
+    drop(watchdog);
+
+    match &error {
+        Some(error) => println!("failed: {}", error),
+        None => println!(
+            "{}",
+            format_timing(elapsed, stats.as_ref(), memory.as_ref())
+        ),
+    }
+
+    let parse_record = PartRecord {
+        day,
+        part: PARSE_STEP,
+        answer: None,
+        elapsed,
+        status: if error.is_some() {
+            Status::Failed
+        } else {
+            Status::Solved
+        },
+        stats,
+        verdict: None,
+        error,
+    };
+    report::emit(&parse_record);
+
+    let mut records = vec![parse_record];
+    if let Some(parsed) = parsed {
+        records.extend(run_parts(day, &parsed, part_one, part_two, options));
+    }
+    records
+}
+
+/// Combined execution time of all completed steps, including parsing.
+/// In `--bench` mode, this is the sum of the medians.
+pub fn total_elapsed(records: &[PartRecord]) -> Duration {
+    records
+        .iter()
+        .filter(|record| record.status == Status::Solved)
+        .map(|record| record.elapsed)
+        .sum()
+}
+
+/// A cargo command in the repository root, using the cargo that runs this binary if any.
+pub fn cargo() -> Command {
+    let mut cmd = Command::new(env::var_os("CARGO").unwrap_or_else(|| "cargo".into()));
+    cmd.current_dir(crate::root_dir());
+    cmd
+}
+
+/// Where cargo put the running binary. Binaries of a profile end up next to each other, e.g.
+/// in `target/debug` for `dev`.
+pub fn target_dir() -> PathBuf {
+    env::current_exe().unwrap().parent().unwrap().to_path_buf()
+}
+
+/// The cargo profile the runner builds day binaries with: the `profile` setting, or else the
+/// profile the running binary was built with.
+pub fn profile() -> String {
+    config::get()
+        .profile
+        .clone()
+        .unwrap_or_else(current_profile)
+}
+
+/// The cargo profile the running binary was built with.
+pub fn current_profile() -> String {
+    match target_dir().file_name().and_then(|name| name.to_str()) {
+        Some("debug") | None => "dev".to_string(),
+        Some(name) => name.to_string(),
+    }
+}
+
+/// Where cargo puts the binaries built with [`profile`].
+pub fn binary_dir() -> PathBuf {
+    let dir = match profile().as_str() {
+        "dev" | "test" => "debug".to_string(),
+        "bench" => "release".to_string(),
+        name => name.to_string(),
+    };
+    target_dir().with_file_name(dir)
+}
+
+/// Loads the locked answers of `year`, warning instead of failing if the answers file is
+/// unreadable.
+pub fn load_answers(year: Option<i16>) -> Option<AnswerBook> {
+    match AnswerBook::load(year) {
+        Ok(answers) => Some(answers),
+        Err(e) => {
+            eprintln!(
+                "could not read {}, skipping verification: {}",
+                answers::file_name(year),
+                e
+            );
+            None
+        }
+    }
+}
+
+/// Stores the answers of all solved parts in `records` as the correct ones for `input`.
+fn lock_answers(year: Option<i16>, day: u8, input: &str, records: &[PartRecord]) {
+    let file_name = answers::file_name(year);
+    let mut answers = match AnswerBook::load(year) {
+        Ok(answers) => answers,
+        Err(e) => {
+            eprintln!("could not read {}: {}", file_name, e);
+            process::exit(1);
+        }
+    };
+
+    let input_hash = answers::input_hash(input);
+    let locked: Vec<&PartRecord> = records
+        .iter()
+        .filter(|record| record.part != PARSE_STEP && record.status == Status::Solved)
+        .collect();
+    for record in &locked {
+        if let Some(answer) = &record.answer {
+            answers.lock(day, record.part, &input_hash, answer);
+        }
+    }
+
+    match answers.save(year) {
+        Ok(_) => {
+            println!("---");
+            println!(
+                "🔒 Locked {} answer(s) for day {:02} in \"{}\".",
+                locked.len(),
+                day,
+                file_name
+            );
+        }
+        Err(e) => {
+            eprintln!("could not write {}: {}", file_name, e);
+            process::exit(1);
+        }
+    }
+}
+
+/// Entry point for the per-day binaries in `src/bin/`.
+pub fn run(solution: &dyn Solution) {
+    run_with_args(solution, pico_args::Arguments::from_env());
+}
+
+/// Fails if `args` holds anything the command did not ask for, e.g. a misspelled flag.
+pub fn finish(args: pico_args::Arguments) -> Result<(), pico_args::Error> {
+    let remaining = args.finish();
+    if remaining.is_empty() {
+        return Ok(());
+    }
+    let remaining: Vec<String> = remaining
+        .iter()
+        .map(|arg| arg.to_string_lossy().into_owned())
+        .collect();
+    Err(pico_args::Error::ArgumentParsingFailed {
+        cause: format!("unexpected argument(s): {}", remaining.join(" ")),
+    })
+}
+
+/// Parses the flags of `cargo solve <day>`. Returns whether to `--watch`, and the options.
+fn solve_args(mut args: pico_args::Arguments) -> Result<(bool, RunOptions), pico_args::Error> {
+    let watch = args.contains("--watch");
+    config::color_from_args(&mut args)?;
+    let options = RunOptions::from_args(&mut args)?;
+    finish(args)?;
+    if watch && options.lock {
+        return Err(pico_args::Error::ArgumentParsingFailed {
+            cause: "--lock cannot be used with --watch".into(),
+        });
+    }
+    Ok((watch, options))
+}
+
+/// Runs a day with the flags in `args`, as `cargo solve <day>` and `aoc solve <day>` do.
+pub fn run_with_args(solution: &dyn Solution, args: pico_args::Arguments) {
+    let (watch, mut options) = match solve_args(args) {
+        Ok(parsed) => parsed,
+        Err(e) => {
+            eprintln!("Failed to process arguments: {}", e);
+            process::exit(1);
+        }
+    };
+    if watch {
+        watch::watch(solution.year(), solution.day(), &options);
+    }
+    if options.input == InputSource::Puzzle {
+        options.answers = load_answers(solution.year());
+    }
+    log::set_verbosity(options.verbosity);
+
+    let input = match options.input.read(solution.year(), solution.day()) {
+        Ok(input) => input,
+        Err(e) => {
+            eprintln!("{}", e);
+            process::exit(1);
+        }
+    };
+    let records = solution.run(&input, &options);
+
+    if options.lock {
+        lock_answers(solution.year(), solution.day(), &input, &records);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(args: &[&str]) -> pico_args::Arguments {
+        pico_args::Arguments::from_vec(args.iter().map(|arg| arg.into()).collect())
+    }
+
+    #[test]
+    fn test_input_source_from_args() {
+        let parse = |values: &[&str]| InputSource::from_args(&mut args(values)).ok();
+
+        assert_eq!(parse(&[]), Some(InputSource::Puzzle));
+        assert_eq!(parse(&["--example"]), Some(InputSource::Example));
+        assert_eq!(parse(&["--input", "-"]), Some(InputSource::Stdin));
+        assert_eq!(
+            parse(&["--input", "other/07.txt"]),
+            Some(InputSource::File("other/07.txt".into()))
+        );
+        assert_eq!(parse(&["--example", "--input", "-"]), None);
+    }
+
+    #[test]
+    fn test_lock_requires_puzzle_input() {
+        assert!(RunOptions::from_args(&mut args(&["--lock"])).is_ok());
+        assert!(RunOptions::from_args(&mut args(&["--lock", "--example"])).is_err());
+    }
+
+    #[test]
+    fn test_solve_args() {
+        assert!(solve_args(args(&["--example", "--watch"])).is_ok_and(|(watch, _)| watch));
+        assert!(solve_args(args(&["--lock", "--watch"])).is_err());
+        // a misspelled flag must not quietly run on the puzzle input.
+        assert!(solve_args(args(&["--exmaple"])).is_err());
+        assert!(solve_args(args(&["--bogus-flag"])).is_err());
+    }
+
+    #[test]
+    fn test_parse_days() {
+        assert_eq!(parse_days("9"), Ok(vec![9]));
+        assert_eq!(parse_days("1-7,9"), Ok(vec![1, 2, 3, 4, 5, 6, 7, 9]));
+        assert_eq!(parse_days("9, 3-4,4"), Ok(vec![3, 4, 9]));
+        assert!(parse_days("7-5").is_err());
+        assert!(parse_days("0").is_err());
+        assert!(parse_days("24-26").is_err());
+        assert!(parse_days("1-").is_err());
+    }
+
+    #[test]
+    fn test_part_selection() {
+        let options = RunOptions::from_args(&mut args(&["--part", "2"])).unwrap();
+        assert!(!options.runs_part(1));
+        assert!(options.runs_part(2));
+        assert!(RunOptions::default().runs_part(1));
+        assert!(RunOptions::from_args(&mut args(&["--part", "3"])).is_err());
+    }
+
+    #[test]
+    fn test_panic_while_benchmarking_fails_part() {
+        let options = RunOptions {
+            bench: Some(BenchOptions {
+                warmup: 0,
+                iterations: Some(3),
+                time_budget: Duration::ZERO,
+            }),
+            ..RunOptions::default()
+        };
+        let calls = std::cell::Cell::new(0);
+        let record = solve_part(
+            7,
+            1,
+            |_: &str| {
+                calls.set(calls.get() + 1);
+                assert!(calls.get() < 3, "slower input");
+                Some(1)
+            },
+            "",
+            &options,
+        );
+        assert_eq!(record.status, Status::Failed);
+        assert_eq!(record.answer, None);
+        let error = record.error.unwrap();
+        assert!(
+            error.starts_with("while benchmarking: panicked at"),
+            "{}",
+            error
+        );
+    }
+
+    #[test]
+    fn test_verdict_only_for_solved_or_locked_parts() {
+        let mut answers = AnswerBook::default();
+        answers.lock(7, 2, &answers::input_hash(""), "24933642");
+        let options = RunOptions {
+            answers: Some(answers),
+            input_hash: Some(answers::input_hash("")),
+            ..RunOptions::default()
+        };
+        let solved = solve_part(7, 1, |_: &str| Some(1), "", &options);
+        assert_eq!(solved.verdict, Some(Verdict::Unverified));
+        let unsolved = solve_part(7, 1, |_: &str| None::<u32>, "", &options);
+        assert_eq!(unsolved.verdict, None);
+        let regressed = solve_part(7, 2, |_: &str| None::<u32>, "", &options);
+        assert_eq!(
+            regressed.verdict,
+            Some(Verdict::Wrong {
+                expected: "24933642".into()
+            })
+        );
+    }
+
+    #[test]
+    fn test_options_roundtrip_through_args() {
+        let options = RunOptions::from_args(&mut args(&[
+            "--input",
+            "-",
+            "--bench",
+            "--part",
+            "2",
+            "-v",
+            "--part-timeout",
+            "2.5",
+        ]))
+        .unwrap();
+        let values = options.to_args();
+        let values: Vec<&str> = values.iter().map(String::as_str).collect();
+        assert_eq!(RunOptions::from_args(&mut args(&values)).unwrap(), options);
+    }
+}
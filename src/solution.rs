@@ -0,0 +1,129 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use crate::report::PartRecord;
+use crate::runner::RunOptions;
+
+/// A solved day, as collected in the registries in `./days/mod.rs` and `./years/mod.rs`.
+///
+/// Days do not implement this trait by hand: calling `solution!` at the bottom of a day module
+/// generates the implementation from its functions.
+///
+/// -   `solution!(<day>)` passes the raw input to `part_one(&str)` and `part_two(&str)`.
+/// -   `solution!(<day>, parse)` calls `parse(&str)` once and passes a reference to its result
+///     to both parts. The runner times the parse step separately from the parts.
+/// -   `solution!(<day>, parse?)` does the same for a `parse` that returns a `Result`. If it
+///     fails, the error is reported and the parts are skipped.
+///
+/// Days of a separate year, see [`separate_year`](crate::separate_year), pass it last:
+/// `solution!(<day>, parse, year = 2021)`.
+///
+/// Parts return either an `Option` or a `Result`, see [`IntoOutcome`](crate::IntoOutcome).
+///
+/// `solution!` also generates a test that runs the day against every example file with
+/// expected answers in its front-matter, see [`example::check`](crate::example::check).
+pub trait Solution {
+    fn day(&self) -> u8;
+    /// The separate year this day belongs to, `None` for the main year.
+    fn year(&self) -> Option<i16> {
+        None
+    }
+    /// Runs and prints all steps of this day against `input`.
+    fn run(&self, input: &str, options: &RunOptions) -> Vec<PartRecord>;
+}
+
+#[macro_export]
+macro_rules! solution {
+    (@parsed $year:expr, $day:expr, $parse:expr) => {
+        /// Registry entry for this day, see `advent_of_code::years::days`.
+        pub struct Day;
+
+        impl $crate::Solution for Day {
+            fn day(&self) -> u8 {
+                $day
+            }
+
+            fn year(&self) -> Option<i16> {
+                $year
+            }
+
+            fn run(
+                &self,
+                input: &str,
+                options: &$crate::runner::RunOptions,
+            ) -> Vec<$crate::report::PartRecord> {
+                $crate::runner::run_parsed_day(
+                    $day,
+                    input,
+                    $parse,
+                    |parsed| part_one(parsed),
+                    |parsed| part_two(parsed),
+                    options,
+                )
+            }
+        }
+
+        #[cfg(test)]
+        mod solution_examples {
+            #[test]
+            fn test_examples() {
+                $crate::example::check(&super::Day);
+            }
+        }
+    };
+    (@raw $year:expr, $day:expr) => {
+        /// Registry entry for this day, see `advent_of_code::years::days`.
+        pub struct Day;
+
+        impl $crate::Solution for Day {
+            fn day(&self) -> u8 {
+                $day
+            }
+
+            fn year(&self) -> Option<i16> {
+                $year
+            }
+
+            fn run(
+                &self,
+                input: &str,
+                options: &$crate::runner::RunOptions,
+            ) -> Vec<$crate::report::PartRecord> {
+                $crate::runner::run_day(
+                    $day,
+                    input,
+                    |input| part_one(input),
+                    |input| part_two(input),
+                    options,
+                )
+            }
+        }
+
+        #[cfg(test)]
+        mod solution_examples {
+            #[test]
+            fn test_examples() {
+                $crate::example::check(&super::Day);
+            }
+        }
+    };
+    ($day:expr, year = $year:literal) => {
+        $crate::solution!(@raw Some($year), $day);
+    };
+    ($day:expr, $parse:ident, year = $year:literal) => {
+        $crate::solution!(@parsed Some($year), $day, |input| Ok::<_, std::convert::Infallible>($parse(input)));
+    };
+    ($day:expr, $parse:ident ?, year = $year:literal) => {
+        $crate::solution!(@parsed Some($year), $day, $parse);
+    };
+    ($day:expr) => {
+        $crate::solution!(@raw None, $day);
+    };
+    ($day:expr, $parse:ident) => {
+        $crate::solution!(@parsed None, $day, |input| Ok::<_, std::convert::Infallible>($parse(input)));
+    };
+    ($day:expr, $parse:ident ?) => {
+        $crate::solution!(@parsed None, $day, $parse);
+    };
+}
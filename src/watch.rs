@@ -0,0 +1,227 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use crate::config;
+use crate::report::{self, PartRecord, Status, PARSE_STEP, REPORT_FILE_ENV};
+use crate::runner::{self, RunOptions};
+use crate::{ANSI_BOLD, ANSI_RESET};
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+use std::process::Command;
+use std::time::{Duration, SystemTime};
+use std::{env, fs, process, thread};
+
+/// How often the watched files are checked for changes.
+const POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+/// The files a day depends on: its module, its binary, the shared helpers, its input and all
+/// of its examples.
+fn watched_files(year: Option<i16>, day: u8) -> Vec<PathBuf> {
+    let src = crate::root_dir().join("src");
+    let module = crate::module_path(year, day).replace("::", "/");
+    let mut files = vec![
+        src.join(format!("{}.rs", module)),
+        src.join("bin")
+            .join(format!("{}.rs", crate::bin_name(year, day))),
+        src.join("helpers.rs"),
+        crate::year_file_path(year, "inputs", day),
+    ];
+    // listed on every poll, so examples added while watching are picked up.
+    if let Ok(entries) = fs::read_dir(crate::year_folder_path(year, "examples")) {
+        let prefix = format!("{:02}", day);
+        files.extend(
+            entries
+                .filter_map(|entry| entry.ok())
+                .map(|entry| entry.path())
+                .filter(|path| {
+                    path.file_name()
+                        .and_then(|name| name.to_str())
+                        .is_some_and(|name| name.starts_with(&prefix) && name.ends_with(".txt"))
+                }),
+        );
+    }
+    files
+}
+
+/// Modification times of the watched files. Missing files are included, so creating or
+/// deleting one counts as a change.
+fn snapshot(year: Option<i16>, day: u8) -> BTreeMap<PathBuf, Option<SystemTime>> {
+    watched_files(year, day)
+        .into_iter()
+        .map(|path| {
+            let modified = fs::metadata(&path).and_then(|meta| meta.modified()).ok();
+            (path, modified)
+        })
+        .collect()
+}
+
+/// Rebuilds the day's binary with the profile it was built with. Compiler errors are printed
+/// as they come.
+fn build(year: Option<i16>, day: u8) -> bool {
+    runner::cargo()
+        .args(["build", "--quiet", "--bin", &crate::bin_name(year, day)])
+        .args(["--profile", &runner::profile()])
+        .status()
+        .is_ok_and(|status| status.success())
+}
+
+/// Runs the example tests of the day, printing their output only if they fail.
+fn test_examples(year: Option<i16>, day: u8) {
+    let output = runner::cargo()
+        .args([
+            "test",
+            "--quiet",
+            "--lib",
+            &format!("{}::", crate::module_path(year, day)),
+        ])
+        .output();
+    match output {
+        Ok(output) if output.status.success() => println!("✔ examples pass"),
+        Ok(output) => {
+            println!("✘ examples fail");
+            print!("{}", String::from_utf8_lossy(&output.stdout));
+            eprint!("{}", String::from_utf8_lossy(&output.stderr));
+        }
+        Err(e) => eprintln!("could not run cargo test: {}", e),
+    }
+}
+
+/// Runs the freshly built binary of the day and returns its records.
+fn run_day(year: Option<i16>, day: u8, options: &RunOptions) -> Vec<PartRecord> {
+    let bin_name = crate::bin_name(year, day);
+    let binary = runner::binary_dir().join(format!("{}{}", bin_name, env::consts::EXE_SUFFIX));
+    let report_path =
+        env::temp_dir().join(format!("aoc_watch_{}_{}.jsonl", process::id(), bin_name));
+
+    #[allow(unused_must_use)]
+    {
+        fs::remove_file(&report_path);
+    }
+
+    if let Err(e) = Command::new(&binary)
+        .args(options.to_args())
+        .env(REPORT_FILE_ENV, &report_path)
+        .env(config::COLOR_ENV, config::child_color())
+        .status()
+    {
+        eprintln!("could not run {}: {}", binary.display(), e);
+    }
+
+    let records = fs::read_to_string(&report_path)
+        .map(|contents| report::read_records(&contents))
+        .unwrap_or_default();
+
+    #[allow(unused_must_use)]
+    {
+        fs::remove_file(&report_path);
+    }
+    records
+}
+
+fn describe(record: &PartRecord) -> String {
+    match record.status {
+        Status::Solved => match &record.answer {
+            Some(answer) if answer.contains('\n') => "a multi-line answer".into(),
+            Some(answer) => answer.clone(),
+            None => "nothing".into(),
+        },
+        Status::Unsolved => "not solved".into(),
+        Status::Failed => "failed".into(),
+    }
+}
+
+/// One line per part, telling how its result changed since the `previous` run.
+pub fn answer_changes(previous: &[PartRecord], current: &[PartRecord]) -> Vec<String> {
+    current
+        .iter()
+        .filter(|record| record.part != PARSE_STEP)
+        .map(|record| {
+            let before = previous
+                .iter()
+                .find(|previous| previous.part == record.part);
+            match before {
+                Some(before)
+                    if (&before.answer, before.status) == (&record.answer, record.status) =>
+                {
+                    format!("Part {}: unchanged", record.part)
+                }
+                Some(before) => format!(
+                    "Part {}: {} → {}",
+                    record.part,
+                    describe(before),
+                    describe(record)
+                ),
+                None => format!("Part {}: {}", record.part, describe(record)),
+            }
+        })
+        .collect()
+}
+
+/// Rebuilds and re-runs a day whenever one of the files it depends on changes. Never returns,
+/// stop it with Ctrl-C.
+pub fn watch(year: Option<i16>, day: u8, options: &RunOptions) -> ! {
+    let mut seen = snapshot(year, day);
+    let mut previous: Option<Vec<PartRecord>> = None;
+
+    loop {
+        println!("----------");
+        if build(year, day) {
+            test_examples(year, day);
+            let records = run_day(year, day, options);
+            if let Some(previous) = &previous {
+                println!("{}Since the last run:{}", ANSI_BOLD, ANSI_RESET);
+                for change in answer_changes(previous, &records) {
+                    println!("  {}", change);
+                }
+            }
+            previous = Some(records);
+        } else {
+            println!("✘ build failed");
+        }
+        match year {
+            Some(year) => println!("👀 Watching day {:02} of {} for changes...", day, year),
+            None => println!("👀 Watching day {:02} for changes...", day),
+        }
+
+        loop {
+            thread::sleep(POLL_INTERVAL);
+            let current = snapshot(year, day);
+            if current != seen {
+                seen = current;
+                break;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::report::part_record;
+
+    #[test]
+    fn test_answer_changes() {
+        let previous = [
+            part_record(7, PARSE_STEP, None, Duration::ZERO, Status::Solved),
+            part_record(7, 1, Some("95437"), Duration::ZERO, Status::Solved),
+            part_record(7, 2, None, Duration::ZERO, Status::Unsolved),
+        ];
+        let current = [
+            part_record(7, PARSE_STEP, None, Duration::ZERO, Status::Solved),
+            part_record(7, 1, Some("95437"), Duration::ZERO, Status::Solved),
+            part_record(7, 2, Some("24933642"), Duration::ZERO, Status::Solved),
+        ];
+        assert_eq!(
+            answer_changes(&previous, &current),
+            vec!["Part 1: unchanged", "Part 2: not solved → 24933642"]
+        );
+        assert_eq!(
+            answer_changes(
+                &[],
+                &[part_record(7, 1, None, Duration::ZERO, Status::Failed)]
+            ),
+            vec!["Part 1: failed"]
+        );
+    }
+}